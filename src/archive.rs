@@ -0,0 +1,115 @@
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{self, Error, ErrorKind, Read},
+};
+
+use crate::{deserialize_screen, format_date, DateField, NodeID, Query, Screen, TagDB};
+
+// a completion of an archived node, along with the tags on it and its
+// ancestors at the time, so that done plots keep counting it
+#[derive(Debug, Clone)]
+pub struct ArchivedDone {
+    pub time: u64,
+    pub tags: Vec<String>,
+}
+
+impl ArchivedDone {
+    // whether the completion matches a query, as far as its tags and
+    // finish time can tell. content, creation and the like are gone
+    pub fn matches(&self, query: &Query) -> bool {
+        match *query {
+            Query::And(ref a, ref b) => self.matches(a) && self.matches(b),
+            Query::Or(ref a, ref b) => self.matches(a) || self.matches(b),
+            Query::Not(ref q) => !self.matches(q),
+            Query::Tag(_) | Query::Value(..) | Query::Like(..) => {
+                let tags: HashSet<String> = self.tags.iter().cloned().collect();
+                TagDB::matches_tags(&tags, query)
+            },
+            Query::Done => true,
+            Query::State(ref state) => state == "done",
+            Query::Date(DateField::Finish, cmp, date) => cmp.holds(self.time, date),
+            _ => false,
+        }
+    }
+}
+
+pub fn archive_path(work_path: &str) -> String { format!("{}.archive", work_path) }
+
+// loads the archive kept next to work_path, which is a regular db
+// whose top level nodes are archived subtrees
+pub fn load_archive(work_path: &str) -> io::Result<Screen> {
+    let path = archive_path(work_path);
+    let mut data = vec![];
+    match File::open(&path) {
+        Ok(mut f) => {
+            f.read_to_end(&mut data)?;
+        },
+        Err(ref e) if e.kind() == ErrorKind::NotFound => {},
+        Err(e) => return Err(e),
+    }
+    let mut archive = if data.is_empty() {
+        let mut archive = Screen::default();
+        archive.nodes.get_mut(&0).unwrap().content = "archive".to_owned();
+        archive
+    } else {
+        deserialize_screen(data).map_err(|e| Error::new(ErrorKind::InvalidData, e))?
+    };
+    archive.work_path = Some(path);
+    Ok(archive)
+}
+
+// every node matching the query, or every node without one, as a
+// breadcrumb trail leading up to it
+pub fn search(screen: &Screen, query: Option<&Query>) -> Vec<String> {
+    let ids = match query {
+        Some(query) => screen.run_query(query, 0),
+        None => {
+            let mut ids: Vec<NodeID> = screen.nodes.keys().cloned().filter(|&id| id != 0).collect();
+            ids.sort();
+            ids
+        },
+    };
+
+    let mut results = vec![];
+    for id in ids {
+        let mut trail = vec![];
+        let mut cursor = id;
+        while cursor != 0 {
+            let node = match screen.nodes.get(&cursor) {
+                Some(node) => node,
+                None => break,
+            };
+            trail.push(node.content.clone());
+            // archived subtrees remember where they used to live
+            if let Some(lineage) = node.meta.tags.get("lineage") {
+                trail.push(lineage.clone());
+                break;
+            }
+            cursor = node.parent_id;
+        }
+        trail.reverse();
+        let node = &screen.nodes[&id];
+        let mut result = trail.join(" / ");
+        if let Some(finish_time) = node.meta.finish_time {
+            result.push_str(&format!(" (done {})", format_date(finish_time)));
+        }
+        results.push(result);
+    }
+    results
+}
+
+#[test]
+fn test_archived_done_matches() {
+    let done = ArchivedDone {
+        time: 1_000_000,
+        tags: vec!["cat".to_owned(), "prio=3".to_owned()],
+    };
+    let matches = |q: &str| done.matches(&q.parse::<Query>().unwrap());
+    assert!(matches("#cat and #prio>=2"));
+    assert!(matches("is:done not #dog"));
+    assert!(matches("finish<2000-01-01"));
+    assert!(!matches("#cat and is:open"));
+    assert!(!matches("cat"));
+    assert!(!matches("#prio>3 or #dog"));
+}
@@ -1,4 +1,4 @@
-use clap::{App, Arg};
+use clap::{App, Arg, SubCommand};
 
 const APP_NAME: &str = env!("CARGO_PKG_NAME");
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -11,4 +11,88 @@ pub fn create<'a>() -> App<'a, 'a> {
         .author(AUTHORS)
         .about(ABOUT)
         .arg(Arg::with_name("PATH").takes_value(true).required(false))
+        .subcommand(
+            SubCommand::with_name("archive")
+                .about("moves completed subtrees into an archive next to the db")
+                .arg(
+                    Arg::with_name("older-than")
+                        .long("older-than")
+                        .takes_value(true)
+                        .default_value("30d")
+                        .help("only archive subtrees finished at least this long ago, like 30d"),
+                )
+                .arg(Arg::with_name("PATH").takes_value(true).required(false)),
+        )
+        .subcommand(
+            SubCommand::with_name("query")
+                .about("prints nodes matching a query, written like those after #query")
+                .arg(
+                    Arg::with_name("archive")
+                        .long("archive")
+                        .help("search the archive instead of the db"),
+                )
+                .arg(
+                    Arg::with_name("path")
+                        .long("path")
+                        .takes_value(true)
+                        .help("db to search instead of ~/.void.db"),
+                )
+                .arg(Arg::with_name("TERMS").multiple(true).required(false)),
+        )
+        .subcommand(
+            SubCommand::with_name("next")
+                .about("prints a weighted pick of the next task, like C-v")
+                .arg(
+                    Arg::with_name("minutes")
+                        .long("minutes")
+                        .takes_value(true)
+                        .help("only tasks with an #est= that fits, like 20 or 1h"),
+                )
+                .arg(
+                    Arg::with_name("energy")
+                        .long("energy")
+                        .takes_value(true)
+                        .possible_values(&["low", "high"])
+                        .help("only tasks with a matching #energy="),
+                )
+                .arg(Arg::with_name("PATH").takes_value(true).required(false)),
+        )
+        .subcommand(
+            SubCommand::with_name("export")
+                .about("prints the db in another format")
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&["ics", "todotxt", "taskwarrior"])
+                        .required(true)
+                        .help(
+                            "ics for an iCalendar of due tasks and tracked time, todotxt for a \
+                             todo.txt file of tasks, taskwarrior for `task import` json",
+                        ),
+                )
+                .arg(Arg::with_name("PATH").takes_value(true).required(false)),
+        )
+        .subcommand(
+            SubCommand::with_name("import")
+                .about("merges tasks from another format into the db")
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&["todotxt", "taskwarrior"])
+                        .required(true)
+                        .help(
+                            "todotxt for a todo.txt file, matched on void:<id>, taskwarrior for \
+                             `task export` json, matched on uuid",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("FILE")
+                        .takes_value(true)
+                        .required(true)
+                        .help("file to import, or - for stdin"),
+                )
+                .arg(Arg::with_name("PATH").takes_value(true).required(false)),
+        )
 }
@@ -1,6 +1,14 @@
 use fs2::FileExt;
-use std::{ffi::OsString, fs::OpenOptions, io::Read};
-use voidmap::{deserialize_screen, init_screen_log, Config, Screen};
+use std::{
+    ffi::OsString,
+    fs::{File, OpenOptions},
+    io::{self, Read},
+    process,
+};
+use voidmap::{
+    dateparse, deserialize_screen, ics, init_screen_log, load_archive, parse_taskwarrior, search,
+    taskwarrior, todotxt, Config, Query, Screen, TaskFit,
+};
 
 mod cli;
 
@@ -9,11 +17,30 @@ fn main() {
     let app = cli::create();
     let matches = app.get_matches();
 
-    // Initialise screen logger
-    init_screen_log().unwrap();
-
     let path: OsString = matches
-        .value_of("PATH")
+        .subcommand_matches("archive")
+        .and_then(|m| m.value_of("PATH"))
+        .or_else(|| {
+            matches
+                .subcommand_matches("export")
+                .and_then(|m| m.value_of("PATH"))
+        })
+        .or_else(|| {
+            matches
+                .subcommand_matches("next")
+                .and_then(|m| m.value_of("PATH"))
+        })
+        .or_else(|| {
+            matches
+                .subcommand_matches("import")
+                .and_then(|m| m.value_of("PATH"))
+        })
+        .or_else(|| {
+            matches
+                .subcommand_matches("query")
+                .and_then(|m| m.value_of("path"))
+        })
+        .or_else(|| matches.value_of("PATH"))
         .map(OsString::from)
         .or_else(|| {
             dirs::home_dir().and_then(|mut h| {
@@ -23,6 +50,67 @@ fn main() {
         })
         .unwrap();
 
+    if let Some(query) = matches.subcommand_matches("query") {
+        let terms: Vec<&str> = query.values_of("TERMS").into_iter().flatten().collect();
+        let terms = terms.join(" ");
+        // the same language as #query, where plain words match the content
+        let parsed = if terms.trim().is_empty() {
+            None
+        } else {
+            Some(terms.parse::<Query>().unwrap_or_else(|e| {
+                eprintln!("can't parse query {:?}: {}", terms, e);
+                process::exit(1);
+            }))
+        };
+        let path = path.into_string().unwrap();
+        let screen = if query.is_present("archive") {
+            load_archive(&path).unwrap_or_else(|e| {
+                eprintln!("failed to load archive: {}", e);
+                process::exit(1);
+            })
+        } else {
+            load_read_only(&path)
+        };
+        for result in search(&screen, parsed.as_ref()) {
+            println!("{}", result);
+        }
+        return;
+    }
+
+    if let Some(next) = matches.subcommand_matches("next") {
+        let words = next
+            .value_of("minutes")
+            .into_iter()
+            .chain(next.value_of("energy"));
+        let fit = TaskFit::parse(words).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        });
+        let screen = load_read_only(&path.into_string().unwrap());
+        match screen.pick_task(0, &fit) {
+            Some((task, _)) => println!("{}", screen.nodes[&task].content),
+            None => {
+                eprintln!("no open tasks fit {}", fit);
+                process::exit(1);
+            },
+        }
+        return;
+    }
+
+    if let Some(export) = matches.subcommand_matches("export") {
+        let screen = load_read_only(&path.into_string().unwrap());
+        match export.value_of("format") {
+            Some("ics") => print!("{}", ics(&screen)),
+            Some("todotxt") => print!("{}", todotxt(&screen)),
+            Some("taskwarrior") => print!("{}", taskwarrior(&screen)),
+            _ => unreachable!(),
+        }
+        return;
+    }
+
+    // Initialise screen logger
+    init_screen_log().unwrap();
+
     // load from file if present
     let mut data = vec![];
     let mut f = OpenOptions::new()
@@ -42,13 +130,66 @@ fn main() {
     // Initialise the main working screen
     let mut screen = saved_screen.unwrap_or_else(Screen::default);
 
-    screen.work_path = matches
-        .value_of("PATH")
-        .map(|s| s.into())
-        .or_else(|| Some(path.into_string().unwrap()));
+    screen.work_path = Some(path.into_string().unwrap());
 
     let config = Config::maybe_parsed_from_env().unwrap();
     screen.config = config;
 
+    if let Some(archive) = matches.subcommand_matches("archive") {
+        let older_than = archive.value_of("older-than").unwrap();
+        let cutoff = dateparse(older_than.to_owned()).unwrap_or_else(|| {
+            eprintln!(
+                "can't parse --older-than {:?}, try something like 30d",
+                older_than
+            );
+            process::exit(1);
+        });
+        match screen.archive_stricken(0, cutoff) {
+            Ok(archived) => println!("archived {} completed subtrees", archived),
+            Err(e) => {
+                eprintln!("failed to archive: {}", e);
+                process::exit(1);
+            },
+        }
+        return;
+    }
+
+    if let Some(import) = matches.subcommand_matches("import") {
+        let file = import.value_of("FILE").unwrap();
+        let mut text = String::new();
+        let read = if file == "-" {
+            io::stdin().read_to_string(&mut text)
+        } else {
+            File::open(file).and_then(|mut f| f.read_to_string(&mut text))
+        };
+        if let Err(e) = read {
+            eprintln!("failed to read {}: {}", file, e);
+            process::exit(1);
+        }
+        let (updated, created) = match import.value_of("format") {
+            Some("todotxt") => screen.import_todotxt(&text),
+            Some("taskwarrior") => match parse_taskwarrior(&text) {
+                Ok(tasks) => screen.import_taskwarrior(&tasks),
+                Err(e) => {
+                    eprintln!("can't parse {} as taskwarrior json: {}", file, e);
+                    process::exit(1);
+                },
+            },
+            _ => unreachable!(),
+        };
+        screen.save();
+        println!("updated {} tasks, created {}", updated, created);
+        return;
+    }
+
     screen.run();
 }
+
+// reads the db without locking it, so a running void doesn't get in the way
+fn load_read_only(path: &str) -> Screen {
+    let mut data = vec![];
+    if let Ok(mut f) = File::open(path) {
+        f.read_to_end(&mut data).unwrap();
+    }
+    deserialize_screen(data).unwrap_or_else(|_| Screen::default())
+}
@@ -32,6 +32,7 @@ pub enum Action {
     ToggleCompleted,
     ToggleHideCompleted,
     Arrow,
+    BlockArrow,
     AutoArrange,
     ToggleCollapsed,
     Quit,
@@ -39,7 +40,18 @@ pub enum Action {
     ToggleShowLogs,
     EnterCmd,
     FindTask,
+    SkipTask,
     YankPasteNode,
+    AliasPasteNode,
+    Agenda,
+    StartTimer,
+    StopTimer,
+    Pomodoro,
+    Archive,
+    MoveCardLeft,
+    MoveCardRight,
+    CycleState,
+    Review,
     RaiseSelected,
     LowerSelected,
     Search,
@@ -71,6 +83,7 @@ fn to_action(input: String) -> Option<Action> {
         "toggle_completed" => Some(Action::ToggleCompleted),
         "toggle_hide_completed" => Some(Action::ToggleHideCompleted),
         "arrow" => Some(Action::Arrow),
+        "block_arrow" => Some(Action::BlockArrow),
         "auto_arrange" => Some(Action::AutoArrange),
         "toggle_collapsed" => Some(Action::ToggleCollapsed),
         "quit" => Some(Action::Quit),
@@ -78,7 +91,18 @@ fn to_action(input: String) -> Option<Action> {
         "toggle_show_logs" => Some(Action::ToggleShowLogs),
         "enter_command" => Some(Action::EnterCmd),
         "find_task" => Some(Action::FindTask),
+        "skip_task" => Some(Action::SkipTask),
         "yank_paste_node" => Some(Action::YankPasteNode),
+        "alias_paste_node" => Some(Action::AliasPasteNode),
+        "agenda" => Some(Action::Agenda),
+        "start_timer" => Some(Action::StartTimer),
+        "stop_timer" => Some(Action::StopTimer),
+        "pomodoro" => Some(Action::Pomodoro),
+        "archive" => Some(Action::Archive),
+        "move_card_left" => Some(Action::MoveCardLeft),
+        "move_card_right" => Some(Action::MoveCardRight),
+        "cycle_state" => Some(Action::CycleState),
+        "review" => Some(Action::Review),
         "raise_selected" => Some(Action::RaiseSelected),
         "lower_selected" => Some(Action::LowerSelected),
         "search" => Some(Action::Search),
@@ -124,6 +148,28 @@ fn to_key(raw_key: String) -> Option<Key> {
 #[derive(Debug, Clone)]
 pub struct Config {
     config: HashMap<Key, Action>,
+    // extra weight per day within a week of a task's deadline, from DUE_BOOST
+    pub due_boost: usize,
+    // extra weight per week since a task was created, from AGE_WEIGHT
+    pub age_weight: usize,
+    // extra weight per week since a task was last modified, from STALE_WEIGHT
+    pub stale_weight: usize,
+    // extra weight per time auto-task passed over a task, from SKIP_WEIGHT
+    pub skip_weight: usize,
+    // strike parents when their last child is struck everywhere, not
+    // just below #autocomplete nodes, when AUTOCOMPLETE is set
+    pub autocomplete: bool,
+    // pomodoro length, from POMODORO_MINUTES
+    pub pomodoro_minutes: u64,
+    // the context auto-task draws tasks for, from CONTEXT or the ctx
+    // command. tasks marked @home or #ctx=home only fit that context
+    pub context: Option<String>,
+    // days without a change before a review asks about a task, from
+    // REVIEW_DAYS
+    pub review_days: u64,
+    // workflow states cycled through in order, from a comma separated
+    // TASK_STATES. done and cancelled are the states that close a task
+    pub states: Vec<String>,
 }
 
 impl Default for Config {
@@ -151,6 +197,7 @@ impl Default for Config {
                 (Ctrl('a'), Action::ToggleCompleted),
                 (Ctrl('h'), Action::ToggleHideCompleted),
                 (Ctrl('r'), Action::Arrow),
+                (Alt('r'), Action::BlockArrow),
                 (Ctrl('p'), Action::AutoArrange),
                 (Ctrl('t'), Action::ToggleCollapsed),
                 (Ctrl('c'), Action::Quit),
@@ -158,7 +205,18 @@ impl Default for Config {
                 (Ctrl('l'), Action::ToggleShowLogs),
                 (Ctrl('e'), Action::EnterCmd),
                 (Ctrl('v'), Action::FindTask),
+                (Alt('v'), Action::SkipTask),
                 (Ctrl('y'), Action::YankPasteNode),
+                (Alt('y'), Action::AliasPasteNode),
+                (Alt('a'), Action::Agenda),
+                (Alt('t'), Action::StartTimer),
+                (Alt('T'), Action::StopTimer),
+                (Alt('o'), Action::Pomodoro),
+                (Alt('x'), Action::Archive),
+                (Alt('<'), Action::MoveCardLeft),
+                (Alt('>'), Action::MoveCardRight),
+                (Alt('s'), Action::CycleState),
+                (Alt('w'), Action::Review),
                 (Ctrl('g'), Action::RaiseSelected),
                 (Ctrl('d'), Action::LowerSelected),
                 (Ctrl('u'), Action::Search),
@@ -170,6 +228,50 @@ impl Default for Config {
             ]
             .into_iter()
             .collect(),
+            due_boost: env::var("DUE_BOOST")
+                .ok()
+                .and_then(|b| b.parse().ok())
+                .unwrap_or(0),
+            age_weight: env::var("AGE_WEIGHT")
+                .ok()
+                .and_then(|w| w.parse().ok())
+                .unwrap_or(0),
+            stale_weight: env::var("STALE_WEIGHT")
+                .ok()
+                .and_then(|w| w.parse().ok())
+                .unwrap_or(0),
+            skip_weight: env::var("SKIP_WEIGHT")
+                .ok()
+                .and_then(|w| w.parse().ok())
+                .unwrap_or(0),
+            autocomplete: env::var("AUTOCOMPLETE").is_ok(),
+            pomodoro_minutes: env::var("POMODORO_MINUTES")
+                .ok()
+                .and_then(|m| m.parse().ok())
+                .unwrap_or(25),
+            context: env::var("CONTEXT")
+                .ok()
+                .map(|c| c.trim_start_matches('@').to_owned())
+                .filter(|c| !c.is_empty()),
+            review_days: env::var("REVIEW_DAYS")
+                .ok()
+                .and_then(|d| d.parse().ok())
+                .unwrap_or(7),
+            states: env::var("TASK_STATES")
+                .ok()
+                .map(|s| {
+                    s.split(',')
+                        .map(|s| s.trim().to_owned())
+                        .filter(|s| !s.is_empty())
+                        .collect::<Vec<_>>()
+                })
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| {
+                    vec!["todo", "doing", "waiting", "done", "cancelled"]
+                        .into_iter()
+                        .map(|s| s.to_owned())
+                        .collect()
+                }),
         }
     }
 }
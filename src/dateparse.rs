@@ -2,7 +2,19 @@ use time;
 
 use regex::Regex;
 
+// the end of 9999-12-31, the last day YYYY-MM-DD can write down
+const LAST_DATE: u64 = 253_402_300_799;
+
 pub fn dateparse(input: String) -> Option<u64> {
+    let now_in_s = time::get_time().sec as u64;
+    // spans reaching back before 1970 can't be represented
+    parse_span(&input).and_then(|span| now_in_s.checked_sub(span))
+}
+
+// the length in seconds of a span like 2y, 3m, 1w, 4d or 12h, using the
+// first of those units found, or None when there isn't one or it's too
+// long to count
+fn parse_span(input: &str) -> Option<u64> {
     lazy_static! {
         static ref YEARS: Regex = Regex::new(r"(?P<y>\d+)y").unwrap();
         static ref MONTHS: Regex = Regex::new(r"(?P<m>\d+)m").unwrap();
@@ -11,7 +23,6 @@ pub fn dateparse(input: String) -> Option<u64> {
         static ref HOURS: Regex = Regex::new(r"(?P<h>\d+)h").unwrap();
     }
 
-    let now_in_s = time::get_time().sec as u64;
     let min_in_s = 60;
     let hour_in_s = min_in_s * 60;
     let day_in_s = hour_in_s * 24;
@@ -21,45 +32,162 @@ pub fn dateparse(input: String) -> Option<u64> {
 
     let input = input.trim();
 
-    if let Some(cap) = YEARS.captures(input) {
-        if let Some(years_raw) = cap.get(1) {
-            if let Ok(years) = years_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - years * year_in_s);
-            }
+    let units: [(&Regex, u64); 5] = [
+        (&*YEARS, year_in_s),
+        (&*MONTHS, month_in_s),
+        (&*WEEKS, week_in_s),
+        (&*DAYS, day_in_s),
+        (&*HOURS, hour_in_s),
+    ];
+    for &(re, unit_in_s) in &units {
+        if let Some(cap) = re.captures(input) {
+            return cap[1].parse::<u64>().ok()?.checked_mul(unit_in_s);
         }
     }
 
-    if let Some(cap) = MONTHS.captures(input) {
-        if let Some(months_raw) = cap.get(1) {
-            if let Ok(months) = months_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - months * month_in_s);
-            }
-        }
+    None
+}
+
+// parses a due date, either absolute as YYYY-MM-DD or relative to
+// now like 3d, into midnight (UTC) of that day
+pub fn parse_due(input: &str) -> Option<u64> {
+    let day_in_s = 60 * 60 * 24;
+    if let Ok(tm) = time::strptime(input.trim(), "%Y-%m-%d") {
+        let sec = tm.to_timespec().sec;
+        return if sec >= 0 { Some(sec as u64) } else { None };
     }
+    let now_in_s = time::get_time().sec as u64;
+    let due = now_in_s.checked_add(parse_span(input)?)?;
+    if due > LAST_DATE {
+        return None;
+    }
+    Some(due / day_in_s * day_in_s)
+}
 
-    if let Some(cap) = WEEKS.captures(input) {
-        if let Some(weeks_raw) = cap.get(1) {
-            if let Ok(weeks) = weeks_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - weeks * week_in_s);
-            }
-        }
+pub fn format_date(t: u64) -> String {
+    let tm = time::at_utc(time::Timespec::new(t as i64, 0));
+    time::strftime("%Y-%m-%d", &tm).unwrap()
+}
+
+// the next due time after `now` for a task repeating on `schedule`
+// (1d, 2w, 1m, weekday), stepping forward from `from`
+pub fn next_occurrence(schedule: &str, from: u64, now: u64) -> Option<u64> {
+    lazy_static! {
+        static ref EVERY: Regex = Regex::new(r"^(?P<n>\d+)(?P<unit>[dwm])$").unwrap();
     }
 
-    if let Some(cap) = DAYS.captures(input) {
-        if let Some(days_raw) = cap.get(1) {
-            if let Ok(days) = days_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - days * day_in_s);
-            }
+    let day_in_s = 60 * 60 * 24;
+
+    if schedule == "weekday" {
+        // the epoch fell on a thursday, so sunday is 0 and saturday is 6
+        let is_weekend = |t: u64| matches!((t / day_in_s + 4) % 7, 0 | 6);
+        let mut next = from.checked_add(day_in_s)?;
+        while next <= now || is_weekend(next) {
+            next = next.checked_add(day_in_s)?;
         }
+        return Some(next);
     }
 
-    if let Some(cap) = HOURS.captures(input) {
-        if let Some(hours_raw) = cap.get(1) {
-            if let Ok(hours) = hours_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - hours * hour_in_s);
-            }
-        }
+    let cap = EVERY.captures(schedule.trim())?;
+    let n = cap["n"].parse::<u64>().ok()?;
+    let unit = match &cap["unit"] {
+        "d" => day_in_s,
+        "w" => day_in_s * 7,
+        _ => day_in_s * 30,
+    };
+    // schedules too long to represent never come around again
+    let step = n.checked_mul(unit)?;
+    if step == 0 {
+        return None;
     }
 
-    None
+    let next = from.checked_add(step)?;
+    if next <= now {
+        return next.checked_add((now - next) / step * step + step);
+    }
+    Some(next)
+}
+
+// parses a time estimate like 90m, 2h or 1h30m into minutes
+pub fn parse_estimate(input: &str) -> Option<u64> {
+    lazy_static! {
+        static ref EST: Regex = Regex::new(r"^(?:(?P<h>\d+)h)?(?:(?P<m>\d+)m)?$").unwrap();
+    }
+
+    let cap = EST.captures(input.trim())?;
+    if cap.name("h").is_none() && cap.name("m").is_none() {
+        return None;
+    }
+    let h = cap
+        .name("h")
+        .map_or(Ok(0), |h| h.as_str().parse::<u64>())
+        .ok()?;
+    let m = cap
+        .name("m")
+        .map_or(Ok(0), |m| m.as_str().parse::<u64>())
+        .ok()?;
+    h.checked_mul(60)?.checked_add(m)
+}
+
+pub fn format_estimate(minutes: u64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h{}m", h, m),
+    }
+}
+
+#[test]
+fn test_parse_due() {
+    assert_eq!(parse_due("2026-11-01"), Some(1_793_491_200));
+    assert_eq!(format_date(1_793_491_200), "2026-11-01");
+    assert_eq!(parse_due("soon"), None);
+    let now_in_s = time::get_time().sec as u64;
+    let in_3d = parse_due("3d").unwrap();
+    assert!(in_3d > now_in_s + 60 * 60 * 24 * 2 && in_3d <= now_in_s + 60 * 60 * 24 * 3);
+    assert!(parse_due("60y").unwrap() > now_in_s + 60 * 60 * 24 * 365 * 59);
+    assert_eq!(parse_due("99999999999d"), None);
+    assert_eq!(parse_due("999999999999999d"), None);
+    assert_eq!(parse_due("99999999999999999999d"), None);
+    assert_eq!(dateparse("60y".to_owned()), None);
+    assert_eq!(dateparse("999999999999999d".to_owned()), None);
+    assert!(dateparse("2d".to_owned()).unwrap() < now_in_s);
+}
+
+#[test]
+fn test_next_occurrence() {
+    let day = 60 * 60 * 24;
+    // 1970-01-05 was a monday
+    let monday = 4 * day;
+    assert_eq!(next_occurrence("1d", monday, monday), Some(monday + day));
+    assert_eq!(
+        next_occurrence("2w", monday, monday + day),
+        Some(monday + 14 * day)
+    );
+    assert_eq!(
+        next_occurrence("1d", monday, monday + 3 * day),
+        Some(monday + 4 * day)
+    );
+    assert_eq!(
+        next_occurrence("weekday", monday + 4 * day, monday + 4 * day),
+        Some(monday + 7 * day)
+    );
+    assert_eq!(next_occurrence("0d", monday, monday), None);
+    assert_eq!(next_occurrence("sometimes", monday, monday), None);
+    assert_eq!(next_occurrence("99999999999999999d", monday, monday), None);
+    assert_eq!(next_occurrence("1d", u64::MAX, monday), None);
+}
+
+#[test]
+fn test_parse_estimate() {
+    assert_eq!(parse_estimate("90m"), Some(90));
+    assert_eq!(parse_estimate("2h"), Some(120));
+    assert_eq!(parse_estimate("1h30m"), Some(90));
+    assert_eq!(parse_estimate(""), None);
+    assert_eq!(parse_estimate("a while"), None);
+    assert_eq!(parse_estimate("999999999999999999h"), None);
+    assert_eq!(parse_estimate("99999999999999999999m"), None);
+    assert_eq!(format_estimate(90), "1h30m");
+    assert_eq!(format_estimate(120), "2h");
+    assert_eq!(format_estimate(45), "45m");
 }
@@ -0,0 +1,43 @@
+use std::{fmt, str::FromStr};
+
+use crate::NodeID;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawOutcome {
+    Completed,
+    Skipped,
+    Deleted,
+}
+
+impl fmt::Display for DrawOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            DrawOutcome::Completed => "completed",
+            DrawOutcome::Skipped => "skipped",
+            DrawOutcome::Deleted => "deleted",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for DrawOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<DrawOutcome, String> {
+        match s {
+            "completed" => Ok(DrawOutcome::Completed),
+            "skipped" => Ok(DrawOutcome::Skipped),
+            "deleted" => Ok(DrawOutcome::Deleted),
+            other => Err(format!("unknown draw outcome {:?}", other)),
+        }
+    }
+}
+
+// a task picked by auto-task, and what became of it, if anything
+// happened to it in the session it was drawn in
+#[derive(Debug, Clone)]
+pub struct Draw {
+    pub node_id: NodeID,
+    pub time: u64,
+    pub outcome: Option<DrawOutcome>,
+}
@@ -0,0 +1,134 @@
+use crate::{NodeID, Screen};
+
+// an iCalendar with a VTODO for each open task with a due date, and
+// a VEVENT for each finished interval of tracked time. UIDs come from
+// node ids, so importing a newer export updates the same entries
+pub fn ics(screen: &Screen) -> String {
+    let now = time::get_time().sec as u64;
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_owned(),
+        "VERSION:2.0".to_owned(),
+        "PRODID:-//voidmap//void//EN".to_owned(),
+    ];
+
+    let mut tasks = screen.open_tasks();
+    tasks.sort();
+    for task in tasks {
+        let node = &screen.nodes[&task];
+        let due = match node.meta.due {
+            Some(due) => due,
+            None => continue,
+        };
+        let status = match node.state.as_deref() {
+            Some("doing") => "IN-PROCESS",
+            _ => "NEEDS-ACTION",
+        };
+        lines.push("BEGIN:VTODO".to_owned());
+        lines.push(format!("UID:void-{}@voidmap", task));
+        lines.push(format!("DTSTAMP:{}", ics_time(now)));
+        lines.push(format!("CREATED:{}", ics_time(node.meta.ctime)));
+        lines.push(format!("LAST-MODIFIED:{}", ics_time(node.meta.mtime)));
+        lines.push(format!("SUMMARY:{}", ics_escape(&node.content)));
+        lines.push(format!(
+            "DESCRIPTION:{}",
+            ics_escape(&breadcrumbs(screen, task))
+        ));
+        lines.push(format!("DUE;VALUE=DATE:{}", ics_date(due)));
+        lines.push(format!("STATUS:{}", status));
+        lines.push("END:VTODO".to_owned());
+    }
+
+    let mut ids: Vec<NodeID> = screen.nodes.keys().cloned().collect();
+    ids.sort();
+    for id in ids {
+        let node = &screen.nodes[&id];
+        for &(start, end) in &node.meta.intervals {
+            // a running timer isn't an event yet
+            let end = match end {
+                Some(end) => end,
+                None => continue,
+            };
+            lines.push("BEGIN:VEVENT".to_owned());
+            lines.push(format!("UID:void-{}-{}@voidmap", id, start));
+            lines.push(format!("DTSTAMP:{}", ics_time(now)));
+            lines.push(format!("DTSTART:{}", ics_time(start)));
+            lines.push(format!("DTEND:{}", ics_time(end)));
+            lines.push(format!("SUMMARY:{}", ics_escape(&node.content)));
+            lines.push(format!(
+                "DESCRIPTION:{}",
+                ics_escape(&breadcrumbs(screen, id))
+            ));
+            lines.push("END:VEVENT".to_owned());
+        }
+    }
+    lines.push("END:VCALENDAR".to_owned());
+
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&ics_fold(&line));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+// the contents of a node's ancestors, from the top down
+fn breadcrumbs(screen: &Screen, node_id: NodeID) -> String {
+    let mut trail = vec![];
+    let mut cursor = screen.nodes[&node_id].parent_id;
+    while cursor != 0 {
+        let node = match screen.nodes.get(&cursor) {
+            Some(node) => node,
+            None => break,
+        };
+        trail.push(node.content.clone());
+        cursor = node.parent_id;
+    }
+    trail.reverse();
+    trail.join(" / ")
+}
+
+fn ics_time(t: u64) -> String {
+    let tm = time::at_utc(time::Timespec::new(t as i64, 0));
+    time::strftime("%Y%m%dT%H%M%SZ", &tm).unwrap()
+}
+
+fn ics_date(t: u64) -> String {
+    let tm = time::at_utc(time::Timespec::new(t as i64, 0));
+    time::strftime("%Y%m%d", &tm).unwrap()
+}
+
+fn ics_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+// lines longer than 75 octets are continued on the next line after
+// a space, without splitting any characters
+fn ics_fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            len = 1;
+        }
+        out.push(c);
+        len += c.len_utf8();
+    }
+    out
+}
+
+#[test]
+fn test_ics_escape_and_fold() {
+    assert_eq!(ics_escape("a, b; c\\d\ne"), "a\\, b\\; c\\\\d\\ne");
+    let folded = ics_fold(&"x".repeat(160));
+    let lines: Vec<&str> = folded.split("\r\n").collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0].len(), 75);
+    assert_eq!(lines[1], format!(" {}", "x".repeat(74)));
+    let folded = ics_fold(&"☠".repeat(30));
+    assert!(folded.split("\r\n").all(|l| l.len() <= 75));
+    assert_eq!(folded.replace("\r\n ", ""), "☠".repeat(30));
+}
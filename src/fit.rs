@@ -0,0 +1,88 @@
+use std::fmt;
+
+use crate::parse_estimate;
+
+// what a drawn task has to fit: the minutes at hand, checked against
+// #est=, and the energy at hand, checked against #energy=
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct TaskFit {
+    pub minutes: Option<u64>,
+    pub energy: Option<String>,
+}
+
+impl TaskFit {
+    // reads words like `20m` or `45` (minutes) and `low` or `high`,
+    // in any order
+    pub fn parse<'a, I: Iterator<Item = &'a str>>(words: I) -> Result<TaskFit, String> {
+        let mut fit = TaskFit::default();
+        for word in words {
+            if word == "low" || word == "high" {
+                fit.energy = Some(word.to_owned());
+            } else if let Some(minutes) = word.parse().ok().or_else(|| parse_estimate(word)) {
+                fit.minutes = Some(minutes);
+            } else {
+                return Err(format!(
+                    "can't tell how {:?} fits a task, try 20m, 1h, low or high",
+                    word
+                ));
+            }
+        }
+        Ok(fit)
+    }
+
+    pub fn is_empty(&self) -> bool { self.minutes.is_none() && self.energy.is_none() }
+
+    // whether a task with this estimate and energy fits. strict fits
+    // need both to be known, otherwise unknowns are given the benefit
+    // of the doubt
+    pub fn fits(&self, est: Option<u64>, energy: Option<&str>, strict: bool) -> bool {
+        let time_fits = match (self.minutes, est) {
+            (None, _) => true,
+            (Some(minutes), Some(est)) => est <= minutes,
+            (Some(_), None) => !strict,
+        };
+        let energy_fits = match (self.energy.as_deref(), energy) {
+            (None, _) => true,
+            (Some(wanted), Some(energy)) => wanted == energy,
+            (Some(_), None) => !strict,
+        };
+        time_fits && energy_fits
+    }
+}
+
+impl fmt::Display for TaskFit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.minutes, self.energy.as_deref()) {
+            (Some(minutes), Some(energy)) => write!(f, "{}m at {} energy", minutes, energy),
+            (Some(minutes), None) => write!(f, "{}m", minutes),
+            (None, Some(energy)) => write!(f, "{} energy", energy),
+            (None, None) => write!(f, "anything"),
+        }
+    }
+}
+
+#[test]
+fn test_task_fit() {
+    let fit = TaskFit::parse("low 20m".split_whitespace()).unwrap();
+    assert_eq!(fit.minutes, Some(20));
+    assert_eq!(fit.energy.as_deref(), Some("low"));
+    assert_eq!(fit.to_string(), "20m at low energy");
+    assert_eq!(
+        TaskFit::parse("1h30m".split_whitespace()).unwrap().minutes,
+        Some(90)
+    );
+    assert_eq!(
+        TaskFit::parse("45".split_whitespace()).unwrap().minutes,
+        Some(45)
+    );
+    assert!(TaskFit::parse("sleepy".split_whitespace()).is_err());
+    assert!(TaskFit::parse("".split_whitespace()).unwrap().is_empty());
+
+    assert!(fit.fits(Some(15), Some("low"), true));
+    assert!(!fit.fits(Some(30), Some("low"), true));
+    assert!(!fit.fits(Some(15), Some("high"), false));
+    assert!(!fit.fits(None, Some("low"), true));
+    assert!(fit.fits(None, Some("low"), false));
+    assert!(fit.fits(None, None, false));
+    assert!(TaskFit::default().fits(Some(600), None, true));
+}
@@ -0,0 +1,73 @@
+use std::{
+    io::{self, stdin},
+    sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender},
+    thread,
+    time::Duration,
+};
+
+use termion::{event::Event, input::TermRead};
+
+// terminal events read on a thread of their own, so the run loop can
+// wait for one with a timeout. the thread only reads when asked, which
+// leaves stdin alone while a prompt or an editor has it
+pub struct Input {
+    requests: Sender<()>,
+    events: Receiver<io::Result<Event>>,
+    // whether the thread is already reading an event for us
+    pending: bool,
+}
+
+impl Input {
+    pub fn spawn() -> Input {
+        let (requests, request_rx) = channel();
+        let (event_tx, events) = channel();
+        thread::spawn(move || {
+            let mut stdin_events = stdin().events();
+            for () in request_rx {
+                match stdin_events.next() {
+                    Some(evt) => {
+                        if event_tx.send(evt).is_err() {
+                            break;
+                        }
+                    },
+                    None => break,
+                }
+            }
+        });
+        Input {
+            requests,
+            events,
+            pending: false,
+        }
+    }
+
+    // the next event, or None once stdin is closed
+    pub fn wait(&mut self) -> Option<io::Result<Event>> {
+        self.request();
+        let evt = self.events.recv().ok();
+        self.pending = false;
+        evt
+    }
+
+    // like wait, but gives up after timeout. the read carries on, so
+    // nothing typed in the meantime is lost
+    pub fn wait_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<io::Result<Event>, RecvTimeoutError> {
+        self.request();
+        let evt = self.events.recv_timeout(timeout);
+        if !matches!(evt, Err(RecvTimeoutError::Timeout)) {
+            self.pending = false;
+        }
+        evt
+    }
+
+    fn request(&mut self) {
+        if !self.pending {
+            // a dead thread shows up as a closed channel on receive
+            let _ = self.requests.send(());
+            self.pending = true;
+        }
+    }
+}
@@ -6,35 +6,53 @@ extern crate lazy_static;
 #[macro_use]
 extern crate log;
 
+mod archive;
 mod colors;
 mod config;
 mod dateparse;
+mod draw;
+mod export;
+mod fit;
+mod input;
 mod logging;
 mod meta;
 mod node;
 mod pack;
 mod pb;
 mod plot;
+mod query;
 mod screen;
 mod serialization;
 mod tagdb;
 mod task;
+mod taskwarrior;
+mod todotxt;
 
 use std::{cmp, collections::HashMap};
 
 use regex::Regex;
 
 pub use crate::{
+    archive::{archive_path, load_archive, search, ArchivedDone},
     colors::random_fg_color,
     config::{Action, Config},
-    dateparse::dateparse,
+    dateparse::{
+        dateparse, format_date, format_estimate, next_occurrence, parse_due, parse_estimate,
+    },
+    draw::{Draw, DrawOutcome},
+    export::ics,
+    fit::TaskFit,
+    input::Input,
     logging::init_screen_log,
     meta::Meta,
     node::Node,
     pack::Pack,
+    query::{tag_predicate, Cmp, DateField, Query},
     screen::Screen,
     serialization::{deserialize_screen, serialize_screen},
-    tagdb::TagDB,
+    tagdb::{TagDB, TagValue},
+    taskwarrior::{node_from_uuid, parse_taskwarrior, taskwarrior, TaskwarriorTask},
+    todotxt::{project_name, todotxt, TodoTxtTask},
 };
 
 pub type Coords = (u16, u16);
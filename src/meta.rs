@@ -21,6 +21,14 @@ pub struct Meta {
     pub mtime: u64,
     pub finish_time: Option<u64>,
     pub due: Option<u64>,
+    // completion times of a recurring task, which stays open
+    pub completions: Vec<u64>,
+    // tracked (start, end) times, with no end while the timer runs
+    pub intervals: Vec<(u64, Option<u64>)>,
+    // end times of pomodoros spent on this node
+    pub pomodoros: Vec<u64>,
+    // times auto-task picked this and moved on without it being finished
+    pub skipped: u32,
     pub gps: (f32, f32),
     pub tags: HashMap<String, String>,
 }
@@ -33,6 +41,10 @@ impl Default for Meta {
             mtime: now,
             finish_time: None,
             due: None,
+            completions: vec![],
+            intervals: vec![],
+            pomodoros: vec![],
+            skipped: 0,
             gps: *LOC,
             tags: HashMap::new(),
         }
@@ -46,6 +58,35 @@ impl Meta {
 
     pub fn unfinish(&mut self) { self.finish_time = None; }
 
+    pub fn complete_occurrence(&mut self) { self.completions.push(time::get_time().sec as u64); }
+
+    pub fn start_timer(&mut self) { self.intervals.push((time::get_time().sec as u64, None)); }
+
+    pub fn stop_timer(&mut self) {
+        let now = time::get_time().sec as u64;
+        for interval in &mut self.intervals {
+            if interval.1.is_none() {
+                interval.1 = Some(now);
+            }
+        }
+    }
+
+    pub fn timer_started(&self) -> Option<u64> {
+        self.intervals
+            .iter()
+            .find(|&&(_, end)| end.is_none())
+            .map(|&(start, _)| start)
+    }
+
+    // every time this was finished, including past occurrences of a recurring task
+    pub fn done_times(&self) -> Vec<u64> {
+        self.completions
+            .iter()
+            .chain(self.finish_time.iter())
+            .cloned()
+            .collect()
+    }
+
     pub fn at(&self) -> u64 { self.finish_time.unwrap_or(self.mtime) }
 }
 
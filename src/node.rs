@@ -15,6 +15,10 @@ pub struct Node {
     pub free_text: Option<String>,
     pub color: String,
     pub auto_arrange: bool,
+    // when set, this node renders and completes the target node
+    pub alias_of: Option<NodeID>,
+    // workflow state, when it's been set explicitly
+    pub state: Option<String>,
 }
 
 impl Default for Node {
@@ -33,6 +37,8 @@ impl Default for Node {
             free_text: None,
             color: random_fg_color(),
             auto_arrange: true,
+            alias_of: None,
+            state: None,
         }
     }
 }
@@ -1,3 +1,3 @@
 mod data_model;
 
-pub use self::data_model::{Arrow, Gps, Meta, Node, Screen, Tag};
+pub use self::data_model::{ArchivedDone, Arrow, Draw, Gps, Interval, Meta, Node, Screen, Tag};
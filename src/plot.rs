@@ -45,3 +45,87 @@ where T: Into<i64> {
     }
     plot_sparkline(counts)
 }
+
+// sums the seconds each (start, end) span spends in each bar
+pub fn bounded_duration_sparkline<T>(
+    spans_in: Vec<(T, T)>,
+    start: T,
+    end: T,
+    bars: usize,
+) -> String
+where
+    T: Into<i64>,
+{
+    if bars == 0 {
+        return String::new();
+    }
+
+    let start = start.into();
+    let end = end.into();
+    let step = (end - start) / bars as i64;
+    let mut sums = vec![0; bars];
+
+    if step == 0 || spans_in.is_empty() || end <= start {
+        return String::from_utf8(vec![b' '; bars]).unwrap();
+    }
+
+    for (from, to) in spans_in {
+        let from = cmp::max(from.into(), start);
+        let to = cmp::min(to.into(), end);
+        let mut cursor = from;
+        while cursor < to {
+            let idx = cmp::min((cursor - start) / step, bars as i64 - 1);
+            let bar_end = if idx == bars as i64 - 1 {
+                to
+            } else {
+                cmp::min(start + (idx + 1) * step, to)
+            };
+            sums[idx as usize] += bar_end - cursor;
+            cursor = bar_end;
+        }
+    }
+    plot_sparkline(sums)
+}
+
+// sums the weight of each (from, to, weight) item still open at the end of each
+// bar
+pub fn bounded_remaining_sparkline<T>(
+    items_in: Vec<(T, T, T)>,
+    start: T,
+    end: T,
+    bars: usize,
+) -> String
+where
+    T: Into<i64>,
+{
+    if bars == 0 {
+        return String::new();
+    }
+
+    let start = start.into();
+    let end = end.into();
+    let step = (end - start) / bars as i64;
+
+    if step == 0 || items_in.is_empty() || end <= start {
+        return String::from_utf8(vec![b' '; bars]).unwrap();
+    }
+
+    let items: Vec<(i64, i64, i64)> = items_in
+        .into_iter()
+        .map(|(from, to, weight)| (from.into(), to.into(), weight.into()))
+        .collect();
+    let mut sums = vec![0; bars];
+    for (idx, sum) in sums.iter_mut().enumerate() {
+        let bar_end = if idx == bars - 1 {
+            end
+        } else {
+            start + (idx as i64 + 1) * step
+        };
+        *sum = items
+            .iter()
+            .filter(|&&(from, to, _)| from <= bar_end && bar_end < to)
+            .map(|&(_, _, weight)| weight)
+            .sum();
+    }
+    plot_sparkline(sums)
+}
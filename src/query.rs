@@ -0,0 +1,442 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use regex::Regex;
+
+use crate::{dateparse, parse_due};
+
+// a query over nodes, as written after #query:
+//
+//   query     = or
+//   or        = and ("or" and)*
+//   and       = not ("and"? not)*
+//   not       = "not" not | "(" query ")" | predicate
+//   predicate = #tag | field:value | date-field<op>date | word | "quoted words"
+//
+// fields are content, tag, state, is (open, done or blocked) and the
+// dates ctime, mtime, finish and due, which compare with < <= = >= >
+// against YYYY-MM-DD, today, or a number of days, weeks, months or
+// years like 7d. those count back from now, except for due which
+// counts forward, so `mtime<30d` is stale and `due<3d` is coming up.
+//
+// tags compare by the value after their =, read as a number, duration,
+// date or string like the tag's own value: `#prio>=3`, `#est<1h`,
+// `#due<=7d`, `#client~acme` (contains) or a range like `#prio=2..4`.
+// a plain `#key=value` matches the exact tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+    // case insensitive substring of the content
+    Content(String),
+    // a #tag, or a #key=value pair
+    Tag(String),
+    // a #key= value compared with another of the same type
+    Value(String, Cmp, String),
+    // a #key= value containing some text, ignoring case
+    Like(String, String),
+    State(String),
+    Open,
+    Done,
+    Blocked,
+    Date(DateField, Cmp, u64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateField {
+    Ctime,
+    Mtime,
+    Finish,
+    Due,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cmp {
+    Lt,
+    Le,
+    Eq,
+    Ge,
+    Gt,
+}
+
+impl Cmp {
+    // compares whole days, so `due=today` is anything due today
+    pub fn holds(self, value: u64, date: u64) -> bool {
+        let day_in_sec = 60 * 60 * 24;
+        self.orders((value / day_in_sec).cmp(&(date / day_in_sec)))
+    }
+
+    // whether a value ordered this way against another satisfies self
+    pub fn orders(self, ordering: Ordering) -> bool {
+        match self {
+            Cmp::Lt => ordering == Ordering::Less,
+            Cmp::Le => ordering != Ordering::Greater,
+            Cmp::Eq => ordering == Ordering::Equal,
+            Cmp::Ge => ordering != Ordering::Less,
+            Cmp::Gt => ordering == Ordering::Greater,
+        }
+    }
+}
+
+impl Query {
+    // every one of the queries, or None when there aren't any
+    pub fn all(queries: Vec<Query>) -> Option<Query> {
+        queries
+            .into_iter()
+            .reduce(|acc, q| Query::And(Box::new(acc), Box::new(q)))
+    }
+}
+
+impl FromStr for Query {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Query, String> {
+        let tokens = tokenize(s)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            len: s.chars().count(),
+        };
+        if parser.tokens.is_empty() {
+            return Err("empty query".to_owned());
+        }
+        let query = parser.or()?;
+        // only a stray ')' can stop the parser early
+        match parser.peek() {
+            None => Ok(query),
+            Some((_, at)) => Err(format!("unmatched ')' at column {}", at + 1)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    Word(String),
+    Quoted(String),
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match *self {
+            Token::Open => "'('".to_owned(),
+            Token::Close => "')'".to_owned(),
+            Token::Word(ref word) => format!("{:?}", word),
+            Token::Quoted(ref words) => format!("\"{}\"", words),
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        match *self {
+            Token::Word(ref word) => word.eq_ignore_ascii_case(keyword),
+            _ => false,
+        }
+    }
+}
+
+// tokens along with the column they start at
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, String> {
+    let mut tokens = vec![];
+    let mut chars = input.chars().enumerate().peekable();
+    while let Some((at, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {},
+            '(' => tokens.push((Token::Open, at)),
+            ')' => tokens.push((Token::Close, at)),
+            '"' => {
+                let mut quoted = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, c)) => quoted.push(c),
+                        None => return Err(format!("unterminated quote at column {}", at + 1)),
+                    }
+                }
+                tokens.push((Token::Quoted(quoted), at));
+            },
+            c => {
+                let mut word = c.to_string();
+                while let Some(&(_, c)) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' || c == '"' {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push((Token::Word(word), at));
+            },
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    // where errors at the end of the input point
+    len: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<(&Token, usize)> { self.tokens.get(self.pos).map(|(t, at)| (t, *at)) }
+
+    fn or(&mut self) -> Result<Query, String> {
+        let mut query = self.and()?;
+        while matches!(self.peek(), Some((t, _)) if t.is_keyword("or")) {
+            self.pos += 1;
+            let rhs = self.and()?;
+            query = Query::Or(Box::new(query), Box::new(rhs));
+        }
+        Ok(query)
+    }
+
+    fn and(&mut self) -> Result<Query, String> {
+        let mut query = self.not()?;
+        loop {
+            match self.peek() {
+                Some((t, _)) if t.is_keyword("and") => {
+                    self.pos += 1;
+                },
+                // juxtaposition is an implicit and
+                Some((t, _)) if *t != Token::Close && !t.is_keyword("or") => {},
+                _ => return Ok(query),
+            }
+            let rhs = self.not()?;
+            query = Query::And(Box::new(query), Box::new(rhs));
+        }
+    }
+
+    fn not(&mut self) -> Result<Query, String> {
+        let (token, at) = match self.peek() {
+            Some((token, at)) => (token.clone(), at),
+            None => return Err(format!("expected a query at column {}", self.len + 1)),
+        };
+        self.pos += 1;
+        match token {
+            t if t.is_keyword("not") => Ok(Query::Not(Box::new(self.not()?))),
+            t if t.is_keyword("and") || t.is_keyword("or") => Err(format!(
+                "expected a query before {} at column {}",
+                t.describe(),
+                at + 1
+            )),
+            Token::Open => {
+                let query = self.or()?;
+                match self.peek() {
+                    Some((Token::Close, _)) => {
+                        self.pos += 1;
+                        Ok(query)
+                    },
+                    _ => Err(format!("unclosed '(' at column {}", at + 1)),
+                }
+            },
+            Token::Close => Err(format!("unexpected ')' at column {}", at + 1)),
+            Token::Quoted(words) => Ok(Query::Content(words)),
+            Token::Word(word) => {
+                predicate(&word).map_err(|e| format!("{} at column {}", e, at + 1))
+            },
+        }
+    }
+}
+
+fn predicate(word: &str) -> Result<Query, String> {
+    lazy_static! {
+        static ref RE_FIELD: Regex = Regex::new(r"^([a-z]+)(<=|>=|<|>|=|:)(.*)$").unwrap();
+    }
+    if let Some(tag) = word.strip_prefix('#') {
+        if tag.is_empty() {
+            return Err("expected a tag after '#'".to_owned());
+        }
+        return tag_predicate(tag);
+    }
+    let cap = match RE_FIELD.captures(word) {
+        Some(cap) => cap,
+        None => return Ok(Query::Content(word.to_owned())),
+    };
+    let (field, op, value) = (&cap[1], &cap[2], &cap[3]);
+    if value.is_empty() {
+        return Err(format!("expected a value after {}{}", field, op));
+    }
+    let cmp = match op {
+        "<" => Cmp::Lt,
+        "<=" => Cmp::Le,
+        ">=" => Cmp::Ge,
+        ">" => Cmp::Gt,
+        _ => Cmp::Eq,
+    };
+    let date_field = match field {
+        "ctime" => Some(DateField::Ctime),
+        "mtime" => Some(DateField::Mtime),
+        "finish" => Some(DateField::Finish),
+        "due" => Some(DateField::Due),
+        _ => None,
+    };
+    if let Some(date_field) = date_field {
+        return parse_date(value, date_field == DateField::Due)
+            .map(|date| Query::Date(date_field, cmp, date))
+            .ok_or_else(|| format!("can't parse date {:?}, try 2024-05-01, today or 7d", value));
+    }
+    if cmp != Cmp::Eq {
+        return Err(format!("{} can't be compared with {}", field, op));
+    }
+    match (field, value) {
+        ("content", _) => Ok(Query::Content(value.to_owned())),
+        ("tag", _) => tag_predicate(value.trim_start_matches('#')),
+        ("state", _) => Ok(Query::State(value.to_owned())),
+        ("is", "open") => Ok(Query::Open),
+        ("is", "done") => Ok(Query::Done),
+        ("is", "blocked") => Ok(Query::Blocked),
+        ("is", _) => Err(format!("unknown is:{}, try open, done or blocked", value)),
+        _ => Err(format!(
+            "unknown field {:?}, try content, tag, state, is, ctime, mtime, finish or due",
+            field
+        )),
+    }
+}
+
+// a tag with an optional comparison, as in #tagged=prio>=3
+pub fn tag_predicate(tag: &str) -> Result<Query, String> {
+    lazy_static! {
+        static ref RE_TAG_CMP: Regex = Regex::new(r"^([^<>=~]+)(<=|>=|<|>|~|=)(.*)$").unwrap();
+    }
+    let cap = match RE_TAG_CMP.captures(tag) {
+        Some(cap) => cap,
+        None => return Ok(Query::Tag(tag.to_owned())),
+    };
+    let (key, op, value) = (&cap[1], &cap[2], &cap[3]);
+    if value.is_empty() {
+        return Err(format!("expected a value after #{}{}", key, op));
+    }
+    let compare = |cmp, value: &str| Query::Value(key.to_owned(), cmp, value.to_owned());
+    match op {
+        "<" => Ok(compare(Cmp::Lt, value)),
+        "<=" => Ok(compare(Cmp::Le, value)),
+        ">=" => Ok(compare(Cmp::Ge, value)),
+        ">" => Ok(compare(Cmp::Gt, value)),
+        "~" => Ok(Query::Like(key.to_owned(), value.to_owned())),
+        _ => match value.split_once("..") {
+            Some(("", "")) => Err(format!("expected a range like #{}=1..3", key)),
+            Some(("", hi)) => Ok(compare(Cmp::Le, hi)),
+            Some((lo, "")) => Ok(compare(Cmp::Ge, lo)),
+            Some((lo, hi)) => Ok(Query::And(
+                Box::new(compare(Cmp::Ge, lo)),
+                Box::new(compare(Cmp::Le, hi)),
+            )),
+            None => Ok(Query::Tag(tag.to_owned())),
+        },
+    }
+}
+
+fn parse_date(value: &str, forward: bool) -> Option<u64> {
+    let day_in_sec = 60 * 60 * 24;
+    let now = time::get_time().sec as u64;
+    if value == "today" {
+        return Some(now / day_in_sec * day_in_sec);
+    }
+    let absolute = time::strptime(value, "%Y-%m-%d").is_ok();
+    if forward || absolute {
+        parse_due(value)
+    } else {
+        dateparse(value.to_owned())
+    }
+}
+
+#[test]
+fn test_query_parse() {
+    let q = |s: &str| s.parse::<Query>();
+    let tag = |t: &str| Box::new(Query::Tag(t.to_owned()));
+
+    assert_eq!(
+        q("#a or #b and not #c"),
+        Ok(Query::Or(
+            tag("a"),
+            Box::new(Query::And(tag("b"), Box::new(Query::Not(tag("c")))))
+        ))
+    );
+    assert_eq!(
+        q("(tag:a OR tag:b) #c"),
+        Ok(Query::And(
+            Box::new(Query::Or(tag("a"), tag("b"))),
+            tag("c")
+        ))
+    );
+    assert_eq!(
+        q("\"big report\" state:doing is:open"),
+        Ok(Query::And(
+            Box::new(Query::And(
+                Box::new(Query::Content("big report".to_owned())),
+                Box::new(Query::State("doing".to_owned()))
+            )),
+            Box::new(Query::Open)
+        ))
+    );
+    assert_eq!(
+        q("tag:waiting=bob"),
+        Ok(Query::Tag("waiting=bob".to_owned()))
+    );
+    let value = |k: &str, cmp, v: &str| Box::new(Query::Value(k.to_owned(), cmp, v.to_owned()));
+    assert_eq!(q("#prio>=3"), Ok(*value("prio", Cmp::Ge, "3")));
+    assert_eq!(q("tag:est<1h"), Ok(*value("est", Cmp::Lt, "1h")));
+    assert_eq!(
+        q("#client~acme"),
+        Ok(Query::Like("client".to_owned(), "acme".to_owned()))
+    );
+    assert_eq!(
+        q("#prio=2..4"),
+        Ok(Query::And(
+            value("prio", Cmp::Ge, "2"),
+            value("prio", Cmp::Le, "4")
+        ))
+    );
+    assert_eq!(q("#due=..7d"), Ok(*value("due", Cmp::Le, "7d")));
+    assert_eq!(q("#prio=3"), Ok(Query::Tag("prio=3".to_owned())));
+    assert_eq!(
+        q("#prio>="),
+        Err("expected a value after #prio>= at column 1".to_owned())
+    );
+    assert_eq!(
+        q("due<=2024-05-01"),
+        Ok(Query::Date(
+            DateField::Due,
+            Cmp::Le,
+            parse_due("2024-05-01").unwrap()
+        ))
+    );
+    match q("mtime<30d") {
+        Ok(Query::Date(DateField::Mtime, Cmp::Lt, date)) => {
+            assert!(date < time::get_time().sec as u64)
+        },
+        other => panic!("unexpected {:?}", other),
+    }
+    match q("due<3d") {
+        Ok(Query::Date(DateField::Due, Cmp::Lt, date)) => {
+            assert!(date > time::get_time().sec as u64)
+        },
+        other => panic!("unexpected {:?}", other),
+    }
+
+    assert_eq!(q(""), Err("empty query".to_owned()));
+    assert_eq!(q("#a and (#b"), Err("unclosed '(' at column 8".to_owned()));
+    assert_eq!(q("#a)"), Err("unmatched ')' at column 3".to_owned()));
+    assert_eq!(q("#a or"), Err("expected a query at column 6".to_owned()));
+    assert_eq!(q("not"), Err("expected a query at column 4".to_owned()));
+    assert_eq!(
+        q("or #a"),
+        Err("expected a query before \"or\" at column 1".to_owned())
+    );
+    assert_eq!(
+        q("\"oops"),
+        Err("unterminated quote at column 1".to_owned())
+    );
+    assert!(q("due<soon")
+        .unwrap_err()
+        .starts_with("can't parse date \"soon\""));
+    assert!(q("colour:red")
+        .unwrap_err()
+        .starts_with("unknown field \"colour\""));
+    assert!(q("state<doing").unwrap_err().ends_with("at column 1"));
+
+    assert!(Cmp::Eq.holds(86400 + 5, 86400 + 500));
+    assert!(Cmp::Lt.holds(5, 86400));
+    assert!(!Cmp::Gt.holds(86400, 86400 + 1));
+}
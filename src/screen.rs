@@ -1,12 +1,15 @@
 use std::{
     self,
-    cmp::{max, min},
+    cmp::{max, min, Ordering},
     collections::{BTreeMap, BinaryHeap, HashMap, HashSet},
     env,
     fmt::Write as FmtWrite,
     fs::{remove_file, rename, File, OpenOptions},
     io::{self, stdin, stdout, Error, ErrorKind, Read, Seek, SeekFrom, Stdout, Write},
     process,
+    str::FromStr,
+    sync::mpsc::RecvTimeoutError,
+    time::Duration,
 };
 
 use termion::{
@@ -19,19 +22,29 @@ use termion::{
 };
 
 use rand::{self, Rng};
-use regex::Regex;
+use regex::{Captures, Regex};
 use time;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    cost, dateparse, distances, logging, plot, random_fg_color, re_matches, serialization, Action,
-    Config, Coords, Dir, Node, NodeID, Pack, TagDB,
+    cost, dateparse, distances, format_date, format_estimate, load_archive, logging,
+    next_occurrence, node_from_uuid, parse_due, parse_estimate, plot, project_name,
+    random_fg_color, re_matches, serialization, tag_predicate, Action, ArchivedDone, Config,
+    Coords, DateField, Dir, Draw, DrawOutcome, Input, Node, NodeID, Pack, Query, TagDB, TaskFit,
+    TaskwarriorTask, TodoTxtTask,
 };
 
 pub struct Screen {
     pub max_id: u64,
     pub nodes: HashMap<NodeID, Node>,
     pub arrows: Vec<(NodeID, NodeID)>,
+    // (blocker, blocked) pairs, where blocked can't be worked on
+    // until blocker is complete
+    pub blockers: Vec<(NodeID, NodeID)>,
+    // every task auto-task has picked, oldest first
+    pub draws: Vec<Draw>,
+    // completions that were moved to the archive along with their nodes
+    pub archived_done: Vec<ArchivedDone>,
     pub work_path: Option<String>,
     pub config: Config,
 
@@ -44,7 +57,9 @@ pub struct Screen {
     show_logs: bool,
     selected: Option<NodeID>,
     cut: Option<NodeID>,
+    alias: Option<NodeID>,
     drawing_arrow: Option<NodeID>,
+    drawing_blocker: Option<NodeID>,
     lookup: HashMap<Coords, NodeID>,
     drawn_at: HashMap<NodeID, Coords>,
     dragging_from: Option<Coords>,
@@ -56,9 +71,20 @@ pub struct Screen {
     // when we drill down then pop up, we should go to last focus, stored here
     focus_stack: Vec<(NodeID, NodeID, u16)>,
     last_search: Option<(String, NodeID)>,
-
-    // undo info
-    undo_stack: Vec<NodeID>,
+    // node being worked on and when its pomodoro ends
+    pomodoro: Option<(NodeID, u64)>,
+    // terminal events, once run has started reading them
+    input: Option<Input>,
+    // last task picked by auto_task, which SkipTask passes over
+    last_pick: Option<NodeID>,
+    // the time and energy the last task was drawn for
+    last_fit: TaskFit,
+    // draws from before this time belong to earlier sessions
+    session_start: u64,
+
+    // undo info. each deletion keeps where the node was, and the aliases
+    // it took with it, so they can go back
+    undo_stack: Vec<(NodeID, Vec<Removal>)>,
     // needs to be separate, as recursive deletion of nodes causes ordering issues
     undo_nodes: HashMap<NodeID, Node>,
 
@@ -77,6 +103,11 @@ pub struct Screen {
 
     // grapheme calculation is expensive
     grapheme_cache: HashMap<NodeID, usize>,
+    // minutes of estimated work left under each node, rolled up once
+    // per draw. nodes without an estimate aren't in it
+    estimate_cache: HashMap<NodeID, u64>,
+    // #query errors already logged, so redraws don't repeat them
+    query_errors: HashSet<String>,
 }
 
 impl Default for Screen {
@@ -86,9 +117,14 @@ impl Default for Screen {
         let mut screen = Screen {
             config: Config::default(),
             arrows: vec![],
+            blockers: vec![],
+            draws: vec![],
+            archived_done: vec![],
             selected: None,
             cut: None,
+            alias: None,
             drawing_arrow: None,
+            drawing_blocker: None,
             nodes: HashMap::new(),
             lookup: HashMap::new(),
             drawn_at: HashMap::new(),
@@ -105,6 +141,11 @@ impl Default for Screen {
             focus_stack: vec![],
             is_test: false,
             last_search: None,
+            pomodoro: None,
+            input: None,
+            last_pick: None,
+            last_fit: TaskFit::default(),
+            session_start: time::get_time().sec as u64,
             undo_stack: vec![],
             undo_nodes: HashMap::new(),
             ephemeral_nodes: HashMap::new(),
@@ -112,6 +153,8 @@ impl Default for Screen {
             tag_db: TagDB::default(),
             last_click_ms: 0,
             grapheme_cache: HashMap::new(),
+            estimate_cache: HashMap::new(),
+            query_errors: HashSet::new(),
         };
         screen.nodes.insert(0, root);
         screen
@@ -147,6 +190,16 @@ impl Screen {
         self.nodes.get(&k).map(|node| f(node))
     }
 
+    // like with_node, but also sees the nodes generated for the current
+    // draw, whose ids only last until the next one
+    fn with_any_node<B, F>(&self, k: NodeID, mut f: F) -> Option<B>
+    where F: FnMut(&Node) -> B {
+        self.nodes
+            .get(&k)
+            .or_else(|| self.ephemeral_nodes.get(&k))
+            .map(|node| f(node))
+    }
+
     fn with_node_mut<B, F>(&mut self, k: NodeID, mut f: F) -> Option<B>
     where F: FnMut(&mut Node) -> B {
         self.nodes.get_mut(&k).map(|mut node| {
@@ -162,7 +215,26 @@ impl Screen {
 
     // return of false signals to the caller that we are done in this view
     pub fn handle_event(&mut self, evt: Event) -> bool {
-        match self.config.map(evt) {
+        let action = self.config.map(evt);
+        // generated entries can be moved through, and enter jumps to the
+        // node they stand for, but nothing else can act on them
+        if let Some(selected_id) = self
+            .selected
+            .filter(|s| self.ephemeral_nodes.contains_key(s))
+        {
+            match action {
+                Some(Action::SelectUp)
+                | Some(Action::SelectDown)
+                | Some(Action::SelectLeft)
+                | Some(Action::SelectRight) => {},
+                Some(Action::CreateSibling) => {
+                    self.select_ephemeral(selected_id);
+                    return true;
+                },
+                _ => self.selected = None,
+            }
+        }
+        match action {
             Some(e) => match e {
                 Action::LeftClick(x, y) => {
                     let internal_coords = self.screen_to_internal_xy((x, y));
@@ -208,14 +280,28 @@ impl Screen {
                 Action::ToggleCompleted => self.toggle_stricken(),
                 Action::ToggleHideCompleted => self.toggle_hide_stricken(),
                 Action::Arrow => self.add_or_remove_arrow(),
+                Action::BlockArrow => self.add_or_remove_blocker(),
                 Action::AutoArrange => self.toggle_auto_arrange(),
                 Action::ToggleCollapsed => self.toggle_collapsed(),
                 Action::Quit => return false,
                 Action::Save => self.save(),
                 Action::ToggleShowLogs => self.toggle_show_logs(),
                 Action::EnterCmd => self.enter_cmd(),
-                Action::FindTask => self.auto_task(),
+                Action::FindTask => {
+                    self.auto_task();
+                },
+                Action::SkipTask => self.skip_task(),
                 Action::YankPasteNode => self.cut_paste(),
+                Action::AliasPasteNode => self.alias_paste(),
+                Action::Agenda => self.show_agenda(),
+                Action::StartTimer => self.toggle_timer(),
+                Action::StopTimer => self.stop_timer(),
+                Action::Pomodoro => self.start_pomodoro(),
+                Action::Archive => self.archive_selected(),
+                Action::MoveCardLeft => self.move_card(false),
+                Action::MoveCardRight => self.move_card(true),
+                Action::CycleState => self.cycle_state(),
+                Action::Review => self.review(),
                 Action::RaiseSelected => self.raise_selected(),
                 Action::LowerSelected => self.lower_selected(),
                 Action::Search => self.search_forward(),
@@ -244,6 +330,48 @@ impl Screen {
         }
     }
 
+    fn alias_paste(&mut self) {
+        if let Some(selected_id) = self.selected {
+            if let Some(target) = self.alias.take() {
+                let parent_id = self.resolve_alias(selected_id);
+                self.create_alias(target, parent_id);
+            } else {
+                self.alias = Some(self.resolve_alias(selected_id));
+            }
+        } else if let Some(target) = self.alias.take() {
+            let root = self.drawing_root;
+            self.create_alias(target, root);
+        }
+    }
+
+    fn create_alias(&mut self, target: NodeID, parent_id: NodeID) {
+        if !self.exists(target) || !self.exists(parent_id) {
+            warn!("tried to alias a deleted node");
+            return;
+        }
+        if self.is_parent(target, parent_id) {
+            warn!("can't alias a node inside of itself");
+            return;
+        }
+        let node_id = self.new_node();
+        self.with_node_mut_no_meta(node_id, |node| {
+            node.parent_id = parent_id;
+            node.alias_of = Some(target);
+        });
+        self.with_node_mut_no_meta(parent_id, |parent| parent.children.push(node_id));
+        self.propagate_completion(node_id);
+        self.select_node(node_id);
+    }
+
+    // aliases stand in for their target when completing, editing and
+    // drawing tasks, so most operations should go through this first
+    fn resolve_alias(&self, node_id: NodeID) -> NodeID {
+        self.with_node(node_id, |n| n.alias_of)
+            .unwrap_or(None)
+            .filter(|&target| self.exists(target))
+            .unwrap_or(node_id)
+    }
+
     fn reparent(&mut self, node_id: NodeID, parent_id: NodeID) {
         if !self.exists(node_id) || !self.exists(parent_id) {
             warn!("tried to reparent to deleted node");
@@ -263,35 +391,55 @@ impl Screen {
             self.with_node_mut_no_meta(node_id, |s| s.parent_id = parent_id)
                 .unwrap();
             self.grapheme_cache.remove(&node_id);
+            self.propagate_completion(node_id);
         }
     }
 
-    fn auto_task(&mut self) {
+    fn auto_task(&mut self) -> Option<NodeID> { self.auto_task_fitting(TaskFit::default()) }
+
+    // jumps to a weighted pick among the tasks below the drawing root
+    // that fit the time and energy at hand
+    fn auto_task_fitting(&mut self, fit: TaskFit) -> Option<NodeID> {
+        let picked = self.pick_task(self.drawing_root, &fit);
+        // skipping draws again under the same constraints
+        self.last_fit = fit;
+        let (choice, why) = picked?;
+        info!("picked: {}", why);
+        self.last_pick = Some(choice);
+        self.draws.push(Draw {
+            node_id: choice,
+            time: time::get_time().sec as u64,
+            outcome: None,
+        });
+        self.zoom_select(choice);
+        Some(choice)
+    }
+
+    // a weighted random pick among the open, unblocked leaves below
+    // `from` that suit the current context and `fit`, and why it won
+    pub fn pick_task(&self, from: NodeID, fit: &TaskFit) -> Option<(NodeID, String)> {
         // find all leaf children of incomplete tasks
         // if a parent is complete, the children are complete
         // if all children are complete, but the parent isn't,
         // we need to finish the parent
-        let mut task_roots = vec![];
-        let mut to_explore = vec![self.drawing_root];
-        while let Some(node_id) = to_explore.pop() {
-            let mut node = self.with_node(node_id, |n| n.clone()).unwrap();
-            if node.stricken {
-                // pass
-            } else if node.content.contains("#task") {
-                task_roots.push(node.id);
-            } else {
-                to_explore.append(&mut node.children);
-            }
-        }
+        let mut task_roots = self.task_roots(from);
 
         let mut leaves = vec![];
+        let mut visited = HashSet::new();
         while let Some(root_id) = task_roots.pop() {
+            let root_id = self.resolve_alias(root_id);
+            if !visited.insert(root_id) {
+                continue;
+            }
             let node = self.with_node(root_id, |n| n.clone()).unwrap();
             let mut incomplete_children: Vec<_> = node
                 .children
                 .iter()
-                .cloned()
-                .filter(|&c| self.with_node(c, |c| !c.stricken).unwrap())
+                .map(|&c| self.resolve_alias(c))
+                .filter(|&c| {
+                    self.with_node(c, |c| !c.stricken && !is_template(&c.content))
+                        .unwrap()
+                })
                 .collect();
             if incomplete_children.is_empty() {
                 leaves.push(root_id);
@@ -300,44 +448,482 @@ impl Screen {
             }
         }
 
+        leaves.retain(|&leaf| !self.is_blocked(leaf) && !self.is_waiting(leaf));
+        if let Some(context) = self.config.context.clone() {
+            leaves.retain(|&leaf| self.fits_context(leaf, &context));
+        }
+
         if leaves.is_empty() {
-            info!("no tasks to jump to! create some first");
-            return;
+            match self.config.context {
+                Some(ref context) => info!("no unblocked tasks for @{} to jump to!", context),
+                None => info!("no unblocked tasks to jump to! create some first"),
+            }
+            return None;
         }
 
-        // weight based on priority of most important ancestor
+        let leaves = self.fitting_tasks(leaves, fit);
+        if leaves.is_empty() {
+            info!("no unblocked tasks fit {}", fit);
+            return None;
+        }
 
         let mut prio_pairs = vec![];
         let mut total_prio = 0;
         for &leaf in &leaves {
-            let prio = self
-                .lineage(leaf)
-                .iter()
-                .filter_map(|&p| self.node_priority(p))
-                .max()
-                .unwrap_or(1);
+            let (prio, why) = self.task_weight(leaf);
             total_prio += prio;
-            prio_pairs.push((prio, leaf));
+            prio_pairs.push((prio, leaf, why));
         }
 
         if total_prio == 0 {
             // we're on a page with only zero priority tasks.
             // these are un-selectable automatically.
-            return;
+            return None;
         }
 
         let mut idx: usize = rand::thread_rng().gen_range(0, total_prio);
 
         let mut choice = None;
-        for &(prio, leaf) in &prio_pairs {
+        for (prio, leaf, why) in prio_pairs {
             if prio > idx {
-                choice = Some(leaf);
+                choice = Some((leaf, why));
                 break;
             }
             idx -= prio;
         }
-        let choice = choice.unwrap();
-        self.zoom_select(choice);
+        choice
+    }
+
+    // the tasks whose #est= and #energy= fit, or failing that the ones
+    // that at least don't say they won't
+    fn fitting_tasks(&self, leaves: Vec<NodeID>, fit: &TaskFit) -> Vec<NodeID> {
+        lazy_static! {
+            static ref RE_ENERGY: Regex = Regex::new(r"#energy=(\S+)").unwrap();
+        }
+        if fit.is_empty() {
+            return leaves;
+        }
+        let needs: Vec<(NodeID, Option<u64>, Option<String>)> = leaves
+            .into_iter()
+            .map(|leaf| {
+                let est = self.with_node(leaf, |n| estimate(&n.content)).unwrap();
+                let energy = self.lineage(leaf).into_iter().find_map(|n| {
+                    self.with_node(n, |n| re_matches::<String>(&RE_ENERGY, &n.content))
+                        .and_then(|energies| energies.into_iter().next())
+                });
+                (leaf, est, energy)
+            })
+            .collect();
+
+        let strict: Vec<NodeID> = needs
+            .iter()
+            .filter(|(_, est, energy)| fit.fits(*est, energy.as_deref(), true))
+            .map(|(leaf, ..)| *leaf)
+            .collect();
+        if !strict.is_empty() {
+            return strict;
+        }
+        let lenient: Vec<NodeID> = needs
+            .iter()
+            .filter(|(_, est, energy)| fit.fits(*est, energy.as_deref(), false))
+            .map(|(leaf, ..)| *leaf)
+            .collect();
+        if !lenient.is_empty() {
+            info!(
+                "nothing is known to fit {}, so trying tasks without an estimate or energy",
+                fit
+            );
+        }
+        lenient
+    }
+
+    // tasks without a context anywhere in their lineage fit any context
+    fn fits_context(&self, node_id: NodeID, context: &str) -> bool {
+        let contexts: Vec<String> = self
+            .lineage(node_id)
+            .into_iter()
+            .flat_map(|n| self.with_node(n, |n| contexts(&n.content)).unwrap())
+            .collect();
+        contexts.is_empty() || contexts.iter().any(|c| c == context)
+    }
+
+    // sets the context auto-task draws for, or clears it without one
+    fn set_context(&mut self, context: Option<&str>) {
+        let context = context.map(|c| c.trim_start_matches('@').to_owned());
+        match context {
+            Some(ref context) => info!("context is now @{}", context),
+            None => info!("context cleared"),
+        }
+        self.config.context = context;
+    }
+
+    // weight based on priority of most important ancestor, optionally
+    // boosted by age, staleness, a nearing deadline and being skipped.
+    // returns the weight along with an explanation of it
+    fn task_weight(&self, leaf: NodeID) -> (usize, String) {
+        let now = time::get_time().sec as u64;
+        let day_in_sec = 60 * 60 * 24;
+        let lineage = self.lineage(leaf);
+        let (mut prio, source) = lineage
+            .iter()
+            .filter_map(|&p| self.node_priority(p).map(|prio| (prio, p)))
+            .max_by_key(|&(prio, _)| prio)
+            .unwrap_or((1, leaf));
+        let source = self.with_node(source, |n| n.content.clone()).unwrap();
+        let mut why = vec![format!("prio {} from '{}'", prio, source)];
+        if prio == 0 {
+            // zero priority tasks are never picked, so don't boost them
+            return (prio, why.join(", "));
+        }
+
+        let (ctime, mtime, skipped) = self
+            .with_node(leaf, |n| (n.meta.ctime, n.meta.mtime, n.meta.skipped))
+            .unwrap();
+        let days_old = now.saturating_sub(ctime) / day_in_sec;
+        let days_stale = now.saturating_sub(mtime) / day_in_sec;
+        prio += self.config.age_weight * (days_old / 7) as usize;
+        prio += self.config.stale_weight * (days_stale / 7) as usize;
+        prio += self.config.skip_weight * skipped as usize;
+        if days_old > 0 {
+            why.push(format!("{} days old", days_old));
+        }
+        if days_stale > 0 {
+            why.push(format!("{} days stale", days_stale));
+        }
+        if skipped > 0 {
+            why.push(format!("skipped {} times", skipped));
+        }
+
+        // favor tasks as the closest deadline in their lineage nears
+        let due = lineage
+            .iter()
+            .filter_map(|&p| self.with_node(p, |n| n.meta.due).unwrap_or(None))
+            .min();
+        if let Some(due) = due {
+            let days_left = due.saturating_sub(now) / day_in_sec;
+            if days_left < 7 {
+                prio += self.config.due_boost * (7 - days_left as usize);
+                why.push(format!("due in {} days", days_left));
+            }
+        }
+        why.push(format!("weight {}", prio));
+        (prio, why.join(", "))
+    }
+
+    // passes over the last task auto_task picked, and draws again
+    fn skip_task(&mut self) {
+        trace!("skip_task()");
+        if let Some(last_pick) = self.last_pick.take() {
+            let open = self.with_node(last_pick, |n| !n.stricken).unwrap_or(false);
+            if open {
+                self.with_node_mut_no_meta(last_pick, |n| n.meta.skipped += 1);
+                self.resolve_draw(last_pick, DrawOutcome::Skipped);
+            }
+        }
+        self.auto_task_fitting(self.last_fit.clone());
+    }
+
+    // records what became of a task drawn earlier in this session
+    fn resolve_draw(&mut self, node_id: NodeID, outcome: DrawOutcome) {
+        let session_start = self.session_start;
+        let draw = self
+            .draws
+            .iter_mut()
+            .rev()
+            .find(|d| d.node_id == node_id && d.outcome.is_none() && d.time >= session_start);
+        if let Some(draw) = draw {
+            draw.outcome = Some(outcome);
+        }
+    }
+
+    // takes back an outcome resolve_draw recorded, when what caused it
+    // was undone
+    fn unresolve_draw(&mut self, node_id: NodeID, outcome: DrawOutcome) {
+        let session_start = self.session_start;
+        let draw = self
+            .draws
+            .iter_mut()
+            .rev()
+            .find(|d| d.node_id == node_id && d.time >= session_start);
+        if let Some(draw) = draw.filter(|d| d.outcome == Some(outcome)) {
+            draw.outcome = None;
+        }
+    }
+
+    // picks a task like auto_task, and starts a countdown for working on it
+    fn start_pomodoro(&mut self) {
+        if let Some(task) = self.auto_task() {
+            let now = time::get_time().sec as u64;
+            let end = now + self.config.pomodoro_minutes * 60;
+            self.pomodoro = Some((task, end));
+        }
+    }
+
+    // rings the bell once the pomodoro is over, and asks how it went
+    fn check_pomodoro(&mut self) {
+        let now = time::get_time().sec as u64;
+        match self.pomodoro {
+            Some((_, end)) if now >= end => {},
+            _ => return,
+        }
+        let (task, _) = self.pomodoro.take().unwrap();
+        print!("\x07");
+        let res = self.single_key_prompt("pomodoro over! (d)one / (n)ot done / (s)kip: ");
+        if let Ok(Key::Char(key)) = res {
+            self.pomodoro_answer(task, key);
+        }
+        self.draw();
+    }
+
+    // applies the answer to how a pomodoro went: (d)one and (n)ot done
+    // log it against the task, and (s)kip passes over the task and
+    // starts another pomodoro on the next one drawn
+    fn pomodoro_answer(&mut self, task: NodeID, key: char) {
+        let now = time::get_time().sec as u64;
+        match key {
+            'd' | 'n' if self.exists(task) => {
+                self.with_node_mut_no_meta(task, |n| n.meta.pomodoros.push(now));
+                let stricken = self.with_node(task, |n| n.stricken).unwrap();
+                if key == 'd' && !stricken {
+                    self.toggle_node_stricken(task);
+                    self.propagate_completion(task);
+                }
+            },
+            's' => {
+                self.last_pick = Some(task);
+                self.skip_task();
+                if let Some(next) = self.last_pick {
+                    let end = now + self.config.pomodoro_minutes * 60;
+                    self.pomodoro = Some((next, end));
+                }
+            },
+            _ => {},
+        }
+    }
+
+    // walks through stale, unprioritized and overdue tasks one at a
+    // time, asking what to do with each, then sums up what changed
+    fn review(&mut self) {
+        trace!("review()");
+        let items = self.review_items();
+        if items.is_empty() {
+            info!("review: nothing needs a look");
+            return;
+        }
+        let depth = self.focus_stack.len();
+        let (mut kept, mut reprioritized, mut deferred, mut completed, mut deleted) =
+            (0, 0, 0, 0, 0);
+        for (idx, (node_id, why)) in items.iter().enumerate() {
+            let node_id = *node_id;
+            // it may have gone with an earlier item's subtree
+            if !self.exists(node_id) {
+                continue;
+            }
+            self.zoom_select(node_id);
+            let prompt = format!(
+                "review {}/{} ({}): (k)eep (p)rio (d)efer (c)omplete (x) delete (q)uit: ",
+                idx + 1,
+                items.len(),
+                why
+            );
+            let key = match self.single_key_prompt(&prompt) {
+                Ok(Key::Char('q')) | Ok(Key::Esc) | Err(_) => break,
+                Ok(Key::Char(key)) if "kpdcx".contains(key) => key,
+                Ok(_) => continue,
+            };
+            let answer = match key {
+                'p' => self.prompt("prio (empty to clear): "),
+                'd' => self.prompt("defer until (like 3d or 2024-05-01): "),
+                _ => Ok(Some(String::new())),
+            };
+            let answer = match answer {
+                Ok(Some(answer)) => answer,
+                _ => continue,
+            };
+            if self.review_answer(node_id, key, answer.trim()) {
+                match key {
+                    'k' => kept += 1,
+                    'p' => reprioritized += 1,
+                    'd' => deferred += 1,
+                    'c' => completed += 1,
+                    _ => deleted += 1,
+                }
+            }
+        }
+
+        // back to wherever the review started
+        if self.focus_stack.len() > depth {
+            self.focus_stack.truncate(depth + 1);
+            self.pop_focus();
+        }
+        let summary = format!(
+            "review: {} kept, {} reprioritized, {} deferred, {} completed, {} deleted",
+            kept, reprioritized, deferred, completed, deleted
+        );
+        info!("{}", summary);
+        self.draw();
+        let _ = self.single_key_prompt(&format!("{} (any key)", summary));
+        self.draw();
+    }
+
+    // applies an answer to a review question: (k)eep, (p)rio with the
+    // new priority or nothing to clear it, (d)efer with a due date,
+    // (c)omplete or (x) delete. returns whether it could be applied
+    fn review_answer(&mut self, node_id: NodeID, key: char, answer: &str) -> bool {
+        match key {
+            'k' => {
+                // counts as touched, so it won't be stale again for a while
+                self.with_node_mut(node_id, |_| ());
+            },
+            'p' if answer.is_empty() => self.replace_tag(node_id, "prio", None),
+            'p' if answer.parse::<usize>().is_ok() => self.set_tag(node_id, "prio", answer),
+            'p' => {
+                warn!("can't parse prio {:?}", answer);
+                return false;
+            },
+            'd' => match parse_due(answer) {
+                Some(due) => {
+                    self.set_tag(node_id, "due", &format_date(due));
+                    self.update_due(node_id);
+                },
+                None => {
+                    warn!("can't parse due date {:?}", answer);
+                    return false;
+                },
+            },
+            'c' => {
+                if !self.with_node(node_id, |n| n.stricken).unwrap() {
+                    self.toggle_node_stricken(node_id);
+                    self.propagate_completion(node_id);
+                }
+            },
+            'x' => {
+                self.select_node(node_id);
+                self.delete_selected(false);
+            },
+            _ => return false,
+        }
+        true
+    }
+
+    // #task roots without a #prio=, open tasks untouched for review_days
+    // and overdue tasks, each with why it's up for review
+    fn review_items(&self) -> Vec<(NodeID, String)> {
+        let day_in_sec = 60 * 60 * 24;
+        let now = time::get_time().sec as u64;
+        let today = now / day_in_sec;
+        let stale = now.saturating_sub(self.config.review_days * day_in_sec);
+        let mut items: Vec<(NodeID, Vec<String>)> = vec![];
+        let mut add =
+            |node_id: NodeID, why: String| match items.iter_mut().find(|(id, _)| *id == node_id) {
+                Some((_, whys)) => whys.push(why),
+                None => items.push((node_id, vec![why])),
+            };
+
+        for root in self.task_roots(0) {
+            if !self
+                .with_node(root, |n| n.content.contains("#prio="))
+                .unwrap()
+            {
+                add(root, "no priority".to_owned());
+            }
+        }
+        let mut tasks = self.open_tasks();
+        tasks.sort();
+        for task in tasks {
+            let (mtime, due) = self
+                .with_node(task, |n| (n.meta.mtime, n.meta.due))
+                .unwrap();
+            if mtime < stale {
+                add(
+                    task,
+                    format!("untouched for {}d", (now - mtime) / day_in_sec),
+                );
+            }
+            // like the agenda, anything due today isn't overdue yet
+            if matches!(due, Some(due) if due / day_in_sec < today) {
+                add(task, "overdue".to_owned());
+            }
+        }
+        items
+            .into_iter()
+            .map(|(node_id, whys)| (node_id, whys.join(", ")))
+            .collect()
+    }
+
+    fn task_roots(&self, from: NodeID) -> Vec<NodeID> {
+        let mut task_roots = vec![];
+        let mut to_explore = vec![from];
+        let mut explored = HashSet::new();
+        while let Some(node_id) = to_explore.pop() {
+            // aliases are explored as their target, which is only
+            // counted once no matter how many lists it appears in
+            let node_id = self.resolve_alias(node_id);
+            if !explored.insert(node_id) {
+                continue;
+            }
+            let mut node = self.with_node(node_id, |n| n.clone()).unwrap();
+            if node.stricken || is_template(&node.content) {
+                // pass
+            } else if node.content.contains("#task") {
+                task_roots.push(node.id);
+            } else {
+                to_explore.append(&mut node.children);
+            }
+        }
+        task_roots
+    }
+
+    // every open node beneath a #task root
+    pub fn open_tasks(&self) -> Vec<NodeID> {
+        let mut tasks = vec![];
+        let mut to_visit = self.task_roots(0);
+        let mut visited = HashSet::new();
+        while let Some(node_id) = to_visit.pop() {
+            let node_id = self.resolve_alias(node_id);
+            if !visited.insert(node_id) {
+                continue;
+            }
+            let (open, mut children) = self
+                .with_node(node_id, |n| {
+                    let open = !n.stricken && !is_template(&n.content);
+                    (open, n.children.clone())
+                })
+                .unwrap();
+            if open {
+                tasks.push(node_id);
+                to_visit.append(&mut children);
+            }
+        }
+        tasks
+    }
+
+    // the leaves beneath #task roots, open or not, which are what gets
+    // exported as tasks to other tools
+    pub fn task_leaves(&self) -> Vec<NodeID> {
+        let mut leaves = vec![];
+        let mut to_visit = self.task_roots(0);
+        let mut visited = HashSet::new();
+        while let Some(node_id) = to_visit.pop() {
+            if !visited.insert(node_id) {
+                continue;
+            }
+            let (skip, mut children) = self
+                .with_node(node_id, |n| {
+                    let skip = n.alias_of.is_some() || is_template(&n.content);
+                    (skip, n.children.clone())
+                })
+                .unwrap();
+            if skip {
+                continue;
+            } else if children.is_empty() {
+                leaves.push(node_id);
+            } else {
+                to_visit.append(&mut children);
+            }
+        }
+        leaves.sort();
+        leaves
     }
 
     fn node_priority(&self, node_id: NodeID) -> Option<usize> {
@@ -362,7 +948,6 @@ impl Screen {
             return Err(Error::new(ErrorKind::Other, "can't prompt in test"));
         }
 
-        let stdin: Box<Read> = Box::new(stdin());
         print!(
             "{}{}{}{}",
             cursor::Goto(0, self.dims.1),
@@ -371,7 +956,21 @@ impl Screen {
             prompt
         );
         self.flush();
-        let res = stdin.keys().nth(0).unwrap();
+        let res = match self.input {
+            // the input thread may already be reading the next key
+            Some(ref mut input) => loop {
+                match input.wait() {
+                    Some(Ok(Event::Key(key))) => break Ok(key),
+                    Some(Ok(_)) => {},
+                    Some(Err(e)) => break Err(e),
+                    None => break Err(Error::new(ErrorKind::UnexpectedEof, "stdin closed")),
+                }
+            },
+            None => {
+                let stdin: Box<Read> = Box::new(stdin());
+                stdin.keys().nth(0).unwrap()
+            },
+        };
         debug!("read prompt: {:?}", res);
         print!("{}", style::Reset);
         res
@@ -404,7 +1003,208 @@ impl Screen {
         trace!("enter_cmd()");
         if let Ok(Some(cmd)) = self.prompt("cmd: ") {
             debug!("received command {:?}", cmd);
+            self.exec_cmd(cmd);
+        }
+    }
+
+    fn exec_cmd(&mut self, cmd: String) {
+        let mut words = cmd.split_whitespace();
+        match (words.next(), words.next()) {
+            (Some("sort"), Some(order)) => self.sort_cmd(order, false),
+            (Some("sort!"), Some(order)) => self.sort_cmd(order, true),
+            (Some("new"), Some(name)) => self.instantiate_template(name),
+            (Some("ctx"), context) => self.set_context(context),
+            (Some("next"), _) => match TaskFit::parse(cmd.split_whitespace().skip(1)) {
+                Ok(fit) => {
+                    self.auto_task_fitting(fit);
+                },
+                Err(e) => warn!("{}", e),
+            },
+            _ => warn!("unknown command {:?}", cmd),
+        }
+    }
+
+    // sort tags the selected node (or the drawing root) with #sort=,
+    // while sort! rewrites the stored order of its children
+    fn sort_cmd(&mut self, order: &str, permanent: bool) {
+        let parsed = match order.parse::<SortOrder>() {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                warn!("{}", e);
+                return;
+            },
+        };
+        let node_id = self
+            .selected
+            .map(|s| self.resolve_alias(s))
+            .unwrap_or(self.drawing_root);
+        if permanent {
+            let children = self
+                .with_node(node_id, |n| n.children.clone())
+                .unwrap_or_default();
+            let sorted = self.sort_children(children, parsed);
+            self.with_node_mut_no_meta(node_id, |n| n.children = sorted.clone());
+        } else {
+            self.set_tag(node_id, "sort", order);
+        }
+    }
+
+    // replaces any existing #key= tag on the node with #key=value
+    fn set_tag(&mut self, node_id: NodeID, key: &str, value: &str) {
+        self.replace_tag(node_id, key, Some(value));
+    }
+
+    // replaces any #key= tags on the node with #key=value, or just
+    // removes them when there's no value
+    fn replace_tag(&mut self, node_id: NodeID, key: &str, value: Option<&str>) {
+        let re = Regex::new(&format!(r"\s*#{}=\S*", regex::escape(key))).unwrap();
+        if let Some(content) = self.with_node_mut(node_id, |n| {
+            let mut content = re.replace_all(&n.content, "").trim().to_owned();
+            if let Some(value) = value {
+                if !content.is_empty() {
+                    content.push(' ');
+                }
+                write!(&mut content, "#{}={}", key, value).unwrap();
+            }
+            n.content = content;
+            n.content.clone()
+        }) {
+            self.grapheme_cache.remove(&node_id);
+            self.tag_db.reindex(node_id, content);
+        }
+    }
+
+    // copies the subtree tagged #template=<name> under the selected node
+    // (or the drawing root), expanding {date} and {prompt:X} placeholders
+    fn instantiate_template(&mut self, name: &str) {
+        let tag = format!("template={}", name);
+        let mut templates: Vec<NodeID> = self
+            .tag_db
+            .tag_to_nodes(&tag)
+            .into_iter()
+            .filter(|&n| self.exists(n))
+            .collect();
+        templates.sort();
+        if templates.len() > 1 {
+            warn!(
+                "{} templates named {:?}, using the oldest",
+                templates.len(),
+                name
+            );
+        }
+        if let Some(&template) = templates.first() {
+            let parent_id = self
+                .selected
+                .map(|s| self.resolve_alias(s))
+                .unwrap_or(self.drawing_root);
+            if self.is_parent(template, parent_id) {
+                warn!("can't instantiate a template inside of itself");
+                return;
+            }
+            // each placeholder is only asked for once per instantiation
+            let mut answers = HashMap::new();
+            let copy = self.copy_subtree(template, parent_id, &mut answers);
+            self.propagate_completion(copy);
+        } else {
+            warn!("no template named {:?}", name);
+        }
+    }
+
+    fn copy_subtree(
+        &mut self,
+        node_id: NodeID,
+        parent_id: NodeID,
+        answers: &mut HashMap<String, String>,
+    ) -> NodeID {
+        let src = self.with_node(node_id, |n| n.clone()).unwrap();
+        let content = RE_TEMPLATE.replace_all(&src.content, "").into_owned();
+        let content = self.expand_placeholders(&content, answers);
+        let new_id = self.new_node();
+        self.with_node_mut_no_meta(new_id, |n| {
+            n.parent_id = parent_id;
+            n.content = content.clone();
+            n.collapsed = src.collapsed;
+            n.hide_stricken = src.hide_stricken;
+            n.free_text = src.free_text.clone();
+            n.auto_arrange = src.auto_arrange;
+            n.alias_of = src.alias_of;
+        });
+        self.tag_db.reindex(new_id, content);
+        self.with_node_mut_no_meta(parent_id, |p| p.children.push(new_id));
+        // relative dates in the template count from when it's used
+        self.update_due(new_id);
+        for &child in &src.children {
+            self.copy_subtree(child, new_id, answers);
+        }
+        new_id
+    }
+
+    fn expand_placeholders(&mut self, text: &str, answers: &mut HashMap<String, String>) -> String {
+        lazy_static! {
+            static ref RE_PLACEHOLDER: Regex = Regex::new(r"\{(date|prompt:([^}]+))\}").unwrap();
         }
+        let mut expanded = String::new();
+        let mut last = 0;
+        for cap in RE_PLACEHOLDER.captures_iter(text) {
+            let whole = cap.get(0).unwrap();
+            let key = cap[1].to_owned();
+            if !answers.contains_key(&key) {
+                let answer = if let Some(label) = cap.get(2) {
+                    match self.prompt(&format!("{}: ", label.as_str())) {
+                        Ok(Some(answer)) => answer,
+                        _ => whole.as_str().to_owned(),
+                    }
+                } else {
+                    time::strftime("%Y-%m-%d", &time::now()).unwrap()
+                };
+                answers.insert(key.clone(), answer);
+            }
+            expanded.push_str(&text[last..whole.start()]);
+            expanded.push_str(&answers[&key]);
+            last = whole.end();
+        }
+        expanded.push_str(&text[last..]);
+        expanded
+    }
+
+    fn sort_children(&self, children: Vec<NodeID>, order: SortOrder) -> Vec<NodeID> {
+        let mut keyed: Vec<_> = children
+            .into_iter()
+            .map(|c| (self.sort_value(c, order.field), c))
+            .collect();
+        // nodes missing the field sort last in either direction
+        keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+            (Some(a), Some(b)) if order.descending => b.cmp(a),
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        keyed.into_iter().map(|(_, c)| c).collect()
+    }
+
+    fn sort_value(&self, node_id: NodeID, field: SortField) -> Option<SortValue> {
+        let node_id = self.resolve_alias(node_id);
+        self.with_node(node_id, |n| match field {
+            SortField::Prio => self
+                .node_priority(node_id)
+                .map(|p| SortValue::Num(p as u64)),
+            SortField::Ctime => Some(SortValue::Num(n.meta.ctime)),
+            SortField::Mtime => Some(SortValue::Num(n.meta.mtime)),
+            SortField::Finish => n.meta.finish_time.map(SortValue::Num),
+            SortField::Due => n.meta.due.map(SortValue::Num),
+            SortField::Alpha => {
+                // by what the node says, not the tags it starts with
+                let words: Vec<&str> = n
+                    .content
+                    .split_whitespace()
+                    .filter(|word| !word.starts_with('#'))
+                    .collect();
+                Some(SortValue::Text(words.join(" ").to_lowercase()))
+            },
+            SortField::Done => Some(SortValue::Num(n.stricken as u64)),
+        })
+        .unwrap_or(None)
     }
 
     fn search_forward(&mut self) { self.search(SearchDirection::Forward) }
@@ -676,6 +1476,57 @@ impl Screen {
         ret
     }
 
+    // like recursive_child_filter_map, but skips template subtrees
+    // so that they don't show up in plots
+    fn recursive_stats_filter_map<F, B>(&self, node_id: NodeID, filter_map: &mut F) -> Vec<B>
+    where F: FnMut(&Node) -> Option<B> {
+        let mut ret = vec![];
+        if let Some(node) = self.nodes.get(&node_id) {
+            if is_template(&node.content) {
+                return ret;
+            }
+            if let Some(b) = filter_map(node) {
+                ret.push(b);
+            }
+            for &child_id in &node.children {
+                ret.append(&mut self.recursive_stats_filter_map(child_id, filter_map));
+            }
+        }
+        ret
+    }
+
+    // minutes of estimated work left under node_id, as of the last draw
+    fn remaining_estimate(&self, node_id: NodeID) -> Option<u64> {
+        self.estimate_cache.get(&node_id).cloned()
+    }
+
+    fn cache_estimates(&mut self) {
+        let mut cache = HashMap::new();
+        self.estimate_subtree(0, &mut cache);
+        self.estimate_cache = cache;
+    }
+
+    // sums the estimates of a node's children if any of them have one,
+    // or else uses its own #est=, filling in the cache on the way up
+    fn estimate_subtree(&self, node_id: NodeID, cache: &mut HashMap<NodeID, u64>) -> Option<u64> {
+        let node = self.nodes.get(&node_id)?;
+        let mut from_children: Option<u64> = None;
+        for &child in &node.children {
+            if let Some(est) = self.estimate_subtree(child, cache) {
+                from_children = Some(from_children.unwrap_or(0).saturating_add(est));
+            }
+        }
+        if is_template(&node.content) || node.alias_of.is_some() {
+            return None;
+        }
+        let est = from_children.or_else(|| estimate(&node.content));
+        let est = if node.stricken { est.map(|_| 0) } else { est };
+        if let Some(est) = est {
+            cache.insert(node_id, est);
+        }
+        est
+    }
+
     fn drawable_subtree_dims(&mut self, node_id: NodeID) -> Option<(u16, u16)> {
         if let Some(widths) = self.drawable_subtree_widths(node_id, 0) {
             let height = widths.len() as u16;
@@ -686,16 +1537,20 @@ impl Screen {
         }
     }
 
+    // measures generated entries too, since #agenda, #query and the
+    // like list them as children
     fn drawable_subtree_widths(&mut self, node_id: NodeID, depth: usize) -> Option<Vec<u16>> {
-        let raw_node_opt = self.with_node(node_id, |n| n.clone());
+        let raw_node_opt = self.with_any_node(node_id, |n| n.clone());
         if let Some(raw_node) = raw_node_opt {
             let node = self.format_node(&raw_node);
             let width = 1 + (3 * depth as u16) + node.content.len() as u16;
             let mut ret = vec![width];
-            let hide_stricken = self.with_node(node_id, |n| n.hide_stricken).unwrap();
+            let hide_stricken = raw_node.hide_stricken;
             if !node.collapsed {
                 for &child in &node.children {
-                    let stricken = self.with_node(child, |c| c.stricken).unwrap();
+                    let stricken = self
+                        .with_any_node(self.resolve_alias(child), |c| c.stricken)
+                        .unwrap();
                     if !(hide_stricken && stricken) {
                         // ASSUMES node.children are all valid
                         let mut child_widths =
@@ -728,7 +1583,7 @@ impl Screen {
             let deletable = self
                 .with_node_mut_no_meta(selected_id, |n| {
                     n.selected = false;
-                    n.content.is_empty() && n.children.is_empty()
+                    n.content.is_empty() && n.children.is_empty() && n.alias_of.is_none()
                 })
                 .unwrap_or(false);
             if deletable {
@@ -736,25 +1591,82 @@ impl Screen {
                 return None;
             }
 
-            self.with_node_mut_no_meta(selected_id, |n| {
-                // if parseable date, change date
-                if let Some(date) = re_matches::<String>(&RE_DATE, &*n.content).get(0) {
-                    if let Some(date) = dateparse(date.clone()) {
-                        n.content = RE_DATE.replace(&*n.content, "").trim_end().to_owned();
-                        if n.meta.finish_time.is_some() {
-                            n.meta.finish_time = Some(date);
+            let due = self
+                .with_node_mut_no_meta(selected_id, |n| {
+                    // if parseable date, change date
+                    if let Some(date) = re_matches::<String>(&RE_DATE, &*n.content).get(0) {
+                        // finished nodes date back, open ones are due ahead
+                        let finished = n.meta.finish_time.is_some();
+                        let parsed = if finished {
+                            dateparse(date.clone())
                         } else {
-                            let now_in_s = time::get_time().sec as u64;
-                            let future_date = now_in_s + (now_in_s - date);
-                            n.meta.due = Some(future_date);
+                            parse_due(date)
+                        };
+                        if let Some(date) = parsed {
+                            n.content = RE_DATE.replace(&*n.content, "").trim_end().to_owned();
+                            if finished {
+                                n.meta.finish_time = Some(date);
+                            } else {
+                                return Some(date);
+                            }
                         }
                     }
-                }
-            });
+                    None
+                })
+                .and_then(|due| due);
+
+            let target = self.resolve_alias(selected_id);
+            // [3d] used to set a due date nothing showed. the tag is where
+            // due dates live now, so it's written out as one
+            if let Some(due) = due {
+                self.set_tag(target, "due", &format_date(due));
+            }
+            self.update_due(target);
         }
         self.selected.take()
     }
 
+    // sets meta.due from a #due= tag, or clears it when there isn't one,
+    // rewriting relative dates like #due=3d or #followup=1w as absolute
+    // ones so they don't drift on later edits
+    fn update_due(&mut self, node_id: NodeID) {
+        // templates keep their relative dates for each new copy
+        if self.in_template(node_id) {
+            self.with_node_mut_no_meta(node_id, |n| n.meta.due = None);
+            return;
+        }
+        let due = self.absolute_date_tag(node_id, "due", &RE_DUE);
+        self.with_node_mut_no_meta(node_id, |n| n.meta.due = due);
+        self.absolute_date_tag(node_id, "followup", &RE_FOLLOWUP);
+    }
+
+    fn in_template(&self, node_id: NodeID) -> bool {
+        self.lineage(node_id).into_iter().any(|n| {
+            self.with_node(n, |n| is_template(&n.content))
+                .unwrap_or(false)
+        })
+    }
+
+    // re captures the date of the #key= tag
+    fn absolute_date_tag(&mut self, node_id: NodeID, key: &str, re: &Regex) -> Option<u64> {
+        let raw = self
+            .with_node(node_id, |n| re_matches::<String>(re, &n.content))
+            .and_then(|matches| matches.into_iter().next())?;
+        match parse_due(&raw) {
+            Some(date) => {
+                let absolute = format_date(date);
+                if raw != absolute {
+                    self.set_tag(node_id, key, &absolute);
+                }
+                Some(date)
+            },
+            None => {
+                warn!("can't parse #{}={}", key, raw);
+                None
+            },
+        }
+    }
+
     fn internal_to_screen_xy(&self, coords: Coords) -> Option<Coords> {
         // + 2 compensates for header
         if coords.1 < self.view_y + 2 || coords.1 > self.view_y + self.dims.1 {
@@ -772,54 +1684,212 @@ impl Screen {
         )
     }
 
-    fn coords_are_visible(&self, (_, y): Coords) -> bool {
-        visible(self.view_y + 1, self.dims.1, y)
+    fn coords_are_visible(&self, (_, y): Coords) -> bool {
+        visible(self.view_y + 1, self.dims.1, y)
+    }
+
+    fn node_is_visible(&self, node: NodeID) -> Option<bool> {
+        if let Some(&coords) = self.drawn_at(node) {
+            Some(self.coords_are_visible(coords))
+        } else {
+            None
+        }
+    }
+
+    fn try_select(&mut self, coords: Coords) -> Option<NodeID> {
+        trace!("try_select({:?})", coords);
+        if self.dragging_from.is_none() {
+            self.unselect();
+            if let Some(&node_id) = self.lookup(coords) {
+                return self
+                    .with_node_mut_no_meta(node_id, |node| {
+                        trace!("selected node {} at {:?}", node_id, coords);
+                        node.selected = true;
+                        node_id
+                    })
+                    .and_then(|id| {
+                        self.selected = Some(node_id);
+                        self.dragging_from = Some(coords);
+                        self.dragging_to = Some(coords);
+                        Some(id)
+                    })
+                    .or_else(|| {
+                        trace!("found no node at {:?}", coords);
+                        None
+                    });
+            }
+        } else {
+            self.dragging_to = Some(coords);
+        }
+        trace!("selected no node at {:?}", coords);
+        None
+    }
+
+    fn toggle_stricken(&mut self) {
+        trace!("toggle_stricken()");
+        if let Some(selected_id) = self.selected {
+            let target = self.resolve_alias(selected_id);
+            self.toggle_node_stricken(target);
+            self.propagate_completion(target);
+        }
+    }
+
+    fn toggle_node_stricken(&mut self, node_id: NodeID) {
+        self.with_node_mut_no_meta(node_id, |n| n.state = None);
+        if self.complete_occurrence(node_id) {
+            // the tag is where the due date lives, so it has to move too
+            let due = self.with_node(node_id, |n| n.meta.due).unwrap().unwrap();
+            self.set_tag(node_id, "due", &format_date(due));
+        } else {
+            self.with_node_mut(node_id, |node| node.toggle_stricken());
+            if !self.with_node(node_id, |n| n.stricken).unwrap() {
+                return;
+            }
+        }
+        self.resolve_draw(node_id, DrawOutcome::Completed);
+    }
+
+    fn cycle_state(&mut self) {
+        trace!("cycle_state()");
+        if let Some(selected_id) = self.selected {
+            let target = self.resolve_alias(selected_id);
+            let current = self.state_of(target);
+            let states = &self.config.states;
+            let next = states
+                .iter()
+                .position(|s| *s == current)
+                .map_or(0, |idx| (idx + 1) % states.len());
+            let next = states[next].clone();
+            self.set_state(target, &next);
+        }
+    }
+
+    // the explicit state of a node, or else done for struck nodes
+    // and the first configured state for open ones
+    fn state_of(&self, node_id: NodeID) -> String {
+        self.with_any_node(self.resolve_alias(node_id), |n| {
+            n.state.clone().unwrap_or_else(|| {
+                if n.stricken {
+                    "done".to_owned()
+                } else {
+                    self.config.states[0].clone()
+                }
+            })
+        })
+        .unwrap_or_default()
+    }
+
+    // done and cancelled strike the node, but only done counts as
+    // finished. any other state reopens it
+    fn set_state(&mut self, node_id: NodeID, state: &str) {
+        let closed = state == "done" || state == "cancelled";
+        let stricken = self.with_node(node_id, |n| n.stricken).unwrap();
+        if state == "done" && !stricken {
+            // recurring tasks are rescheduled instead of struck
+            self.toggle_node_stricken(node_id);
+        } else if closed != stricken {
+            self.with_node_mut(node_id, |n| n.toggle_stricken());
+        }
+        self.with_node_mut(node_id, |n| {
+            if n.stricken != closed {
+                return;
+            }
+            match state {
+                "done" if n.meta.finish_time.is_none() => n.meta.finish(),
+                "cancelled" => n.meta.unfinish(),
+                _ => {},
+            }
+            n.state = Some(state.to_owned());
+        });
+        self.propagate_completion(node_id);
+    }
+
+    // with autocomplete on, a parent is struck when its last open child is,
+    // and reopened when one of its children is reopened or added
+    fn propagate_completion(&mut self, node_id: NodeID) {
+        let mut cursor = node_id;
+        while let Some(parent_id) = self.parent(cursor) {
+            if parent_id == 0 || !self.autocomplete_enabled(parent_id) {
+                break;
+            }
+            let (stricken, children) = self
+                .with_node(parent_id, |p| (p.stricken, p.children.clone()))
+                .unwrap();
+            let all_done = !children.is_empty()
+                && children.iter().all(|&c| {
+                    self.with_node(self.resolve_alias(c), |c| c.stricken)
+                        .unwrap_or(true)
+                });
+            if all_done == stricken {
+                break;
+            }
+            self.toggle_node_stricken(parent_id);
+            cursor = parent_id;
+        }
+    }
+
+    fn autocomplete_enabled(&self, node_id: NodeID) -> bool {
+        self.config.autocomplete
+            || self.lineage(node_id).iter().any(|&n| {
+                self.with_node(n, |n| n.content.contains("#autocomplete"))
+                    .unwrap_or(false)
+            })
     }
 
-    fn node_is_visible(&self, node: NodeID) -> Option<bool> {
-        if let Some(&coords) = self.drawn_at(node) {
-            Some(self.coords_are_visible(coords))
-        } else {
-            None
+    // striking a node tagged with #every= records a completion and
+    // moves its due time forward, leaving it open for next time
+    fn complete_occurrence(&mut self, node_id: NodeID) -> bool {
+        lazy_static! {
+            static ref RE_EVERY: Regex = Regex::new(r"#every=(\S+)").unwrap();
         }
+        let now = time::get_time().sec as u64;
+        self.with_node_mut(node_id, |node| {
+            if node.stricken {
+                return false;
+            }
+            let schedule = match re_matches::<String>(&RE_EVERY, &node.content).first() {
+                Some(schedule) => schedule.clone(),
+                None => return false,
+            };
+            let from = node.meta.due.unwrap_or(now);
+            if let Some(next) = next_occurrence(&schedule, from, now) {
+                node.meta.complete_occurrence();
+                node.meta.due = Some(next);
+                true
+            } else {
+                warn!("can't parse #every={}, finishing it instead", schedule);
+                false
+            }
+        })
+        .unwrap_or(false)
     }
 
-    fn try_select(&mut self, coords: Coords) -> Option<NodeID> {
-        trace!("try_select({:?})", coords);
-        if self.dragging_from.is_none() {
-            self.unselect();
-            if let Some(&node_id) = self.lookup(coords) {
-                return self
-                    .with_node_mut_no_meta(node_id, |node| {
-                        trace!("selected node {} at {:?}", node_id, coords);
-                        node.selected = true;
-                        node_id
-                    })
-                    .and_then(|id| {
-                        self.selected = Some(node_id);
-                        self.dragging_from = Some(coords);
-                        self.dragging_to = Some(coords);
-                        Some(id)
-                    })
-                    .or_else(|| {
-                        trace!("found no node at {:?}", coords);
-                        None
-                    });
+    // starts timing the selected node, stopping any other running timer,
+    // or stops it if the selected node is the one being timed
+    fn toggle_timer(&mut self) {
+        if let Some(selected_id) = self.selected {
+            let target = self.resolve_alias(selected_id);
+            let running = self.running_timer();
+            self.stop_timer();
+            if running.map(|(id, _)| id) != Some(target) {
+                self.with_node_mut_no_meta(target, |n| n.meta.start_timer());
             }
-        } else {
-            self.dragging_to = Some(coords);
         }
-        trace!("selected no node at {:?}", coords);
-        None
     }
 
-    fn toggle_stricken(&mut self) {
-        trace!("toggle_stricken()");
-        if let Some(selected_id) = self.selected {
-            self.with_node_mut(selected_id, |node| node.toggle_stricken());
+    fn stop_timer(&mut self) {
+        if let Some((node_id, _)) = self.running_timer() {
+            self.with_node_mut_no_meta(node_id, |n| n.meta.stop_timer());
         }
     }
 
+    fn running_timer(&self) -> Option<(NodeID, u64)> {
+        self.nodes
+            .values()
+            .filter_map(|n| n.meta.timer_started().map(|start| (n.id, start)))
+            .next()
+    }
+
     fn toggle_hide_stricken(&mut self) {
         trace!("toggle_hide_stricken()");
         if let Some(selected_id) = self.selected {
@@ -827,22 +1897,39 @@ impl Screen {
         }
     }
 
-    fn delete_recursive(&mut self, node_id: NodeID) {
+    // returns the aliases deleted along with the subtree, each with the
+    // parent and position it was removed from
+    fn delete_recursive(&mut self, node_id: NodeID) -> Vec<Removal> {
         trace!("delete_recursive({})", node_id);
+        let mut removed_aliases = vec![];
         if let Some(node) = self.nodes.remove(&node_id) {
             // clean up any arrow state
             self.arrows
                 .retain(|&(ref from, ref to)| from != &node_id && to != &node_id);
+            self.blockers
+                .retain(|&(from, to)| from != node_id && to != node_id);
 
             // remove from tag_db
             self.tag_db.remove(node_id);
 
+            self.resolve_draw(node_id, DrawOutcome::Deleted);
+
+            // aliases can't outlive their target
+            let aliases: Vec<NodeID> = self
+                .nodes
+                .values()
+                .filter(|n| n.alias_of == Some(node_id))
+                .map(|n| n.id)
+                .collect();
+            removed_aliases.append(&mut self.delete_aliases(aliases));
+
             for child_id in &node.children {
-                self.delete_recursive(*child_id);
+                removed_aliases.append(&mut self.delete_recursive(*child_id));
             }
 
             self.undo_nodes.insert(node_id, node);
         }
+        removed_aliases
     }
 
     fn delete_selected(&mut self, reselect: bool) {
@@ -850,30 +1937,99 @@ impl Screen {
         if let Some(selected_id) = self.selected.take() {
             let (_, height) = self.drawable_subtree_dims(selected_id).unwrap();
             let coords = self.drawn_at.remove(&selected_id);
+            let mut removed = vec![];
             // remove ref from parent
             if let Some(parent_id) = self.parent(selected_id) {
                 trace!("deleting node {} from parent {}", selected_id, parent_id);
-                self.with_node_mut_no_meta(parent_id, |p| p.children.retain(|c| c != &selected_id))
+                let idx = self
+                    .with_node_mut_no_meta(parent_id, |p| {
+                        let idx = p.children.iter().position(|&c| c == selected_id);
+                        p.children.retain(|c| c != &selected_id);
+                        idx
+                    })
                     .unwrap();
+                if let Some(idx) = idx {
+                    removed.push((selected_id, parent_id, idx));
+                }
             }
             // remove children
-            self.delete_recursive(selected_id);
+            removed.append(&mut self.delete_recursive(selected_id));
             if let Some((x, y)) = coords {
                 if reselect {
                     self.click_select((x, y + height));
                 }
             }
-            self.undo_stack.push(selected_id);
+            self.undo_stack.push((selected_id, removed));
         }
     }
 
     fn undo_delete(&mut self) {
-        if let Some(node_id) = self.undo_stack.pop() {
+        if let Some((node_id, removed)) = self.undo_stack.pop() {
             self.recursive_restore(node_id).unwrap();
+            // in reverse, so earlier positions are right again
+            for &(id, parent_id, idx) in removed.iter().rev() {
+                self.restore_at(id, parent_id, idx);
+            }
+            self.prune_dangling_aliases();
+            for id in self.recursive_child_filter_map(node_id, &mut |n: &Node| Some(n.id)) {
+                self.unresolve_draw(id, DrawOutcome::Deleted);
+            }
             self.select_node(node_id);
         }
     }
 
+    // puts a node back at its old position among its parent's children,
+    // bringing it back from undo_nodes if it's still there
+    fn restore_at(&mut self, node_id: NodeID, parent_id: NodeID, idx: usize) {
+        if !self.nodes.contains_key(&parent_id) {
+            self.undo_nodes.remove(&node_id);
+            return;
+        }
+        if let Some(node) = self.undo_nodes.remove(&node_id) {
+            self.tag_db.reindex(node_id, node.content.clone());
+            self.nodes.insert(node_id, node);
+        }
+        self.with_node_mut_no_meta(parent_id, |p| {
+            p.children.retain(|&c| c != node_id);
+            let idx = min(idx, p.children.len());
+            p.children.insert(idx, node_id);
+        });
+    }
+
+    // restored subtrees may contain aliases to nodes deleted since
+    fn prune_dangling_aliases(&mut self) {
+        let dangling: Vec<NodeID> = self
+            .nodes
+            .values()
+            .filter(|n| n.alias_of.map(|t| !self.exists(t)).unwrap_or(false))
+            .map(|n| n.id)
+            .collect();
+        // their targets are gone for good, so they can't come back either
+        for (alias_id, ..) in self.delete_aliases(dangling) {
+            self.undo_nodes.remove(&alias_id);
+        }
+    }
+
+    fn delete_aliases(&mut self, aliases: Vec<NodeID>) -> Vec<Removal> {
+        let mut removed = vec![];
+        for alias_id in aliases {
+            if let Some(parent_id) = self.parent(alias_id) {
+                let idx = self
+                    .with_node_mut_no_meta(parent_id, |p| {
+                        let idx = p.children.iter().position(|&c| c == alias_id);
+                        p.children.retain(|&c| c != alias_id);
+                        idx
+                    })
+                    .and_then(|idx| idx);
+                if let Some(idx) = idx {
+                    removed.push((alias_id, parent_id, idx));
+                }
+            }
+            removed.append(&mut self.delete_recursive(alias_id));
+        }
+        removed
+    }
+
     fn recursive_restore(&mut self, node_id: NodeID) -> Result<(), ()> {
         if let Some(node) = self.undo_nodes.remove(&node_id) {
             self.with_node_mut_no_meta(node.parent_id, |p| {
@@ -908,9 +2064,26 @@ impl Screen {
         self.start_raw_mode();
         self.dims = terminal_size().unwrap();
         self.draw();
-        let stdin = stdin();
-        for c in stdin.events() {
-            let evt = c.unwrap();
+        self.input = Some(Input::spawn());
+        loop {
+            self.check_pomodoro();
+            let input = self.input.as_mut().unwrap();
+            let evt = if self.pomodoro.is_some() {
+                match input.wait_timeout(Duration::from_secs(1)) {
+                    Ok(evt) => evt.unwrap(),
+                    Err(RecvTimeoutError::Timeout) => {
+                        // nothing typed, so just tick the countdown
+                        self.draw();
+                        continue;
+                    },
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            } else {
+                match input.wait() {
+                    Some(evt) => evt.unwrap(),
+                    None => break,
+                }
+            };
 
             self.dims = terminal_size().unwrap();
 
@@ -948,7 +2121,7 @@ impl Screen {
     fn create_child(&mut self) {
         if let Some(mut selected_id) = self.selected {
             if self
-                .with_node(selected_id, |n| n.content.is_empty())
+                .with_node(self.resolve_alias(selected_id), |n| n.content.is_empty())
                 .unwrap()
             {
                 // we may have hit tab after enter by accident,
@@ -975,7 +2148,8 @@ impl Screen {
                 self.select_node(above);
                 selected_id = above;
             }
-            let selected_id = selected_id;
+            // children of an alias go to its target
+            let selected_id = self.resolve_alias(selected_id);
 
             let node_id = self.new_node();
             self.with_node_mut_no_meta(node_id, |node| node.parent_id = selected_id);
@@ -983,6 +2157,7 @@ impl Screen {
                 selected.children.push(node_id);
             });
             if added.is_some() {
+                self.propagate_completion(node_id);
                 self.select_node(node_id);
             } else {
                 self.delete_recursive(node_id);
@@ -993,7 +2168,7 @@ impl Screen {
     fn create_sibling(&mut self) {
         if let Some(mut selected_id) = self.selected {
             if self
-                .with_node(selected_id, |n| n.content.is_empty())
+                .with_node(self.resolve_alias(selected_id), |n| n.content.is_empty())
                 .unwrap()
             {
                 // we just hit enter twice, so go back a level
@@ -1028,6 +2203,7 @@ impl Screen {
                     parent.children.insert(idx + 1, node_id);
                 });
                 if added.is_some() {
+                    self.propagate_completion(node_id);
                     self.select_node(node_id);
                 } else {
                     self.delete_recursive(node_id);
@@ -1083,7 +2259,8 @@ impl Screen {
     fn backspace(&mut self) {
         trace!("backspace");
         if let Some(selected_id) = self.selected {
-            if let Some(content) = self.with_node_mut(selected_id, |node| {
+            let target = self.resolve_alias(selected_id);
+            if let Some(content) = self.with_node_mut(target, |node| {
                 let content = node.content.clone();
                 let chars = content.chars();
                 let oldlen = chars.clone().count();
@@ -1091,8 +2268,8 @@ impl Screen {
                 node.content = truncated;
                 node.content.clone()
             }) {
-                self.grapheme_cache.remove(&selected_id);
-                self.tag_db.reindex(selected_id, content);
+                self.grapheme_cache.remove(&target);
+                self.tag_db.reindex(target, content);
             }
         }
     }
@@ -1100,12 +2277,13 @@ impl Screen {
     fn append(&mut self, c: char) {
         trace!("append({})", c);
         if let Some(selected_id) = self.selected {
-            if let Some(content) = self.with_node_mut(selected_id, |node| {
+            let target = self.resolve_alias(selected_id);
+            if let Some(content) = self.with_node_mut(target, |node| {
                 node.content.push(c);
                 node.content.clone()
             }) {
-                self.grapheme_cache.remove(&selected_id);
-                self.tag_db.reindex(selected_id, content);
+                self.grapheme_cache.remove(&target);
+                self.tag_db.reindex(target, content);
             }
         }
     }
@@ -1503,6 +2681,12 @@ impl Screen {
     fn select_node(&mut self, node_id: NodeID) {
         trace!("select_node({})", node_id);
         self.unselect();
+        // ephemeral nodes are rebuilt on each draw, which picks the
+        // selection back up from here
+        if self.ephemeral_nodes.contains_key(&node_id) {
+            self.selected = Some(node_id);
+            return;
+        }
         if node_id != 0 {
             // it's possible that unselecting above actually caused
             // this node to be deleted, due to its parent (previous
@@ -1518,12 +2702,32 @@ impl Screen {
         }
     }
 
+    // ephemeral nodes can't be edited, so activating one jumps to the
+    // real node it stands for (or the first one in its group)
+    fn select_ephemeral(&mut self, node_id: NodeID) {
+        let target = self
+            .with_any_node(node_id, |n| {
+                n.alias_of.or_else(|| n.children.first().cloned())
+            })
+            .unwrap_or(None)
+            .map(|t| self.resolve_alias(t));
+        if let Some(target) = target {
+            self.zoom_select(target);
+        }
+    }
+
     fn click_screen(&mut self, coords: Coords) {
         trace!("click_screen({:?})", coords);
         if coords.0 > self.dims.0 || coords.1 > self.view_y + self.dims.1 {
             warn!("click way off-screen");
             return;
         }
+        if let Some(&node_id) = self.lookup(coords) {
+            if self.ephemeral_nodes.contains_key(&node_id) {
+                self.select_ephemeral(node_id);
+                return;
+            }
+        }
         let old = self.selected;
         let new = self.try_select(coords);
         if old.is_none() && self.dragging_from.is_none() {
@@ -1584,16 +2788,295 @@ impl Screen {
             assert!(self.is_parent(0, node_id));
         }
 
+        debug!("testing that all aliases point to existing nodes");
+        for node in self.nodes.values() {
+            if let Some(target) = node.alias_of {
+                assert!(self.nodes.contains_key(&target));
+            }
+        }
+
         debug!("testing that all arrows are existing nodes");
         // no arrows that don't exist
         for &(ref a, ref b) in &self.arrows {
             assert!(self.nodes.get(a).is_some());
             assert!(self.nodes.get(b).is_some());
         }
+        for (a, b) in &self.blockers {
+            assert!(self.nodes.contains_key(a));
+            assert!(self.nodes.contains_key(b));
+        }
+    }
+
+    fn archive_selected(&mut self) {
+        trace!("archive_selected()");
+        let from = self.selected.unwrap_or(self.drawing_root);
+        match self.archive_stricken(from, u64::MAX) {
+            Ok(archived) => info!("archived {} completed subtrees", archived),
+            Err(e) => error!("failed to archive: {}", e),
+        }
+    }
+
+    // moves completed subtrees below `from` that were finished before
+    // `cutoff` into the archive next to work_path, returning how many
+    pub fn archive_stricken(&mut self, from: NodeID, cutoff: u64) -> io::Result<usize> {
+        let work_path = match self.work_path.clone() {
+            Some(work_path) => work_path,
+            None => {
+                warn!("nowhere to archive to without a work path");
+                return Ok(0);
+            },
+        };
+        let roots = self.archivable_roots(from, cutoff);
+        if roots.is_empty() {
+            return Ok(0);
+        }
+
+        let mut archive = load_archive(&work_path)?;
+        let mut archived_done = vec![];
+        for &root in &roots {
+            let parent_id = self.parent(root).unwrap();
+            let breadcrumbs: Vec<String> = self
+                .lineage(parent_id)
+                .into_iter()
+                .map(|n| self.with_node(n, |n| n.content.clone()).unwrap())
+                .collect();
+            let ids = self.recursive_child_filter_map(root, &mut |n: &Node| Some(n.id));
+            for &id in &ids {
+                let mut tags: Vec<String> = self
+                    .lineage(id)
+                    .into_iter()
+                    .flat_map(|n| self.tag_db.node_to_tags(n))
+                    .collect();
+                tags.sort();
+                tags.dedup();
+                let mut node = self.with_node(id, |n| n.clone()).unwrap();
+                for time in node.meta.done_times() {
+                    archived_done.push(ArchivedDone {
+                        time,
+                        tags: tags.clone(),
+                    });
+                }
+                // aliases can't point back into this db once archived
+                if let Some(target) = node.alias_of.take() {
+                    node.content = self.with_node(target, |t| t.content.clone()).unwrap();
+                }
+                node.selected = false;
+                if id == root {
+                    node.parent_id = 0;
+                    node.meta
+                        .tags
+                        .insert("lineage".to_owned(), breadcrumbs.join(" / "));
+                }
+                archive.tag_db.reindex(node.id, node.content.clone());
+                archive.nodes.insert(node.id, node);
+            }
+            archive.nodes.get_mut(&0).unwrap().children.push(root);
+        }
+        archive.max_id = max(archive.max_id, self.max_id);
+
+        // the archive has to be written before the nodes leave this db
+        archive.try_save()?;
+
+        // archived work wasn't thrown away, so deleting it here mustn't
+        // mark any of its draws deleted
+        let draws = self.draws.clone();
+        for &root in &roots {
+            // an alias root may have gone with its target already
+            if !self.exists(root) {
+                continue;
+            }
+            let parent_id = self.parent(root).unwrap();
+            let ids = self.recursive_child_filter_map(root, &mut |n: &Node| Some(n.id));
+            self.with_node_mut_no_meta(parent_id, |p| p.children.retain(|&c| c != root));
+            let aliases = self.delete_recursive(root);
+            for id in ids {
+                self.undo_nodes.remove(&id);
+            }
+            for (alias_id, ..) in aliases {
+                self.undo_nodes.remove(&alias_id);
+            }
+        }
+        self.draws = draws;
+        self.archived_done.extend(archived_done);
+        self.try_save()?;
+        Ok(roots.len())
+    }
+
+    // applies todo.txt lines to the db: lines carrying void:<id> update
+    // that node in place, others become new nodes under their +projects.
+    // returns how many nodes were updated and created
+    pub fn import_todotxt(&mut self, text: &str) -> (usize, usize) {
+        let (mut updated, mut created) = (0, 0);
+        for task in text.lines().filter_map(TodoTxtTask::parse) {
+            if self.import_task(&task).1 {
+                created += 1;
+            } else {
+                updated += 1;
+            }
+        }
+        (updated, created)
+    }
+
+    // taskwarrior tasks are matched on their uuid, which is kept in the
+    // node's meta, and otherwise imported like todo.txt lines
+    pub fn import_taskwarrior(&mut self, tasks: &[TaskwarriorTask]) -> (usize, usize) {
+        let (mut updated, mut created) = (0, 0);
+        for task in tasks {
+            // deleted tasks and recurrence templates have no place here
+            if task.status == "deleted" || task.status == "recurring" {
+                continue;
+            }
+            let existing = self
+                .nodes
+                .values()
+                .find(|n| n.meta.tags.get("uuid") == Some(&task.uuid))
+                .map(|n| n.id)
+                .or_else(|| node_from_uuid(&task.uuid));
+            let (node_id, is_new) = self.import_task(&task.to_todotxt(existing));
+            if is_new {
+                created += 1;
+            } else {
+                updated += 1;
+            }
+            self.with_node_mut_no_meta(node_id, |n| {
+                n.meta.tags.insert("uuid".to_owned(), task.uuid.clone());
+                if let Some(modified) = task.modified {
+                    n.meta.mtime = modified;
+                }
+            });
+        }
+        (updated, created)
     }
 
-    pub fn save(&self) {
-        trace!("save()");
+    // updates the node a task names with its id, or creates a new one
+    // under its projects. returns the node and whether it's new
+    fn import_task(&mut self, task: &TodoTxtTask) -> (NodeID, bool) {
+        let existing = task
+            .id
+            .filter(|&id| id != 0 && self.exists(id))
+            .map(|id| self.resolve_alias(id))
+            .filter(|&id| self.with_node(id, |n| task.exported_from(n)).unwrap());
+        let node_id = match existing {
+            Some(node_id) => node_id,
+            None => {
+                let parent_id = self.project_node(&task.projects);
+                let node_id = self.new_node();
+                self.with_node_mut_no_meta(node_id, |n| {
+                    n.parent_id = parent_id;
+                    if let Some(ctime) = task.created {
+                        n.meta.ctime = ctime;
+                    }
+                });
+                self.with_node_mut_no_meta(parent_id, |p| p.children.push(node_id));
+                node_id
+            },
+        };
+
+        let mut task = task.clone();
+        if let Some(prio) = existing.and_then(|id| self.node_priority(id)) {
+            task.keep_prio(prio);
+        }
+        // unchanged tasks keep their mtime, which staleness goes by
+        let content = task.content();
+        let changed = self
+            .with_node(node_id, |n| {
+                !task.same_content(&n.content) || n.meta.due != task.due
+            })
+            .unwrap();
+        if changed {
+            self.with_node_mut(node_id, |n| {
+                n.content = content.clone();
+                n.meta.due = task.due;
+            });
+            self.grapheme_cache.remove(&node_id);
+            self.tag_db.reindex(node_id, content);
+        }
+
+        let stricken = self.with_node(node_id, |n| n.stricken).unwrap();
+        if stricken != task.done {
+            self.with_node_mut(node_id, |n| {
+                n.state = None;
+                n.toggle_stricken();
+            });
+        }
+        if task.done && task.finished.is_some() {
+            let finish_time = self.with_node(node_id, |n| n.meta.finish_time).unwrap();
+            // todo.txt only keeps the day, so keep our time on that day
+            if finish_time.map(format_date) != task.finished.map(format_date) {
+                self.with_node_mut(node_id, |n| n.meta.finish_time = task.finished);
+            }
+        }
+        (node_id, existing.is_none())
+    }
+
+    // the node that +projects name, creating any that are missing. the
+    // first project is a #task root, and lines without one go to an inbox
+    fn project_node(&mut self, projects: &[String]) -> NodeID {
+        let inbox = ["inbox".to_owned()];
+        let projects = if projects.is_empty() {
+            &inbox[..]
+        } else {
+            projects
+        };
+
+        let existing = self.task_roots(0).into_iter().find(|&root| {
+            self.with_node(root, |n| project_name(&n.content)).unwrap() == projects[0]
+        });
+        let mut cursor =
+            existing.unwrap_or_else(|| self.new_child_node(0, format!("{} #task", projects[0])));
+        for project in &projects[1..] {
+            let children = self.with_node(cursor, |n| n.children.clone()).unwrap();
+            let existing = children.into_iter().find(|&child| {
+                self.with_node(child, |n| project_name(&n.content)).unwrap() == *project
+            });
+            cursor = existing.unwrap_or_else(|| self.new_child_node(cursor, project.clone()));
+        }
+        cursor
+    }
+
+    fn new_child_node(&mut self, parent_id: NodeID, content: String) -> NodeID {
+        let node_id = self.new_node();
+        self.with_node_mut_no_meta(node_id, |n| {
+            n.parent_id = parent_id;
+            n.content = content.clone();
+        });
+        self.with_node_mut_no_meta(parent_id, |p| p.children.push(node_id));
+        self.tag_db.reindex(node_id, content);
+        node_id
+    }
+
+    // the highest completed nodes below `from` with nothing open below them
+    fn archivable_roots(&self, from: NodeID, cutoff: u64) -> Vec<NodeID> {
+        let mut roots = vec![];
+        let mut to_explore = self
+            .with_node(from, |n| n.children.clone())
+            .unwrap_or_default();
+        while let Some(node_id) = to_explore.pop() {
+            let node = self.with_node(node_id, |n| n.clone()).unwrap();
+            let finished = node.meta.finish_time.unwrap_or(node.meta.mtime);
+            let all_done = self
+                .recursive_child_filter_map(node_id, &mut |n: &Node| {
+                    if n.stricken {
+                        None
+                    } else {
+                        Some(n.id)
+                    }
+                })
+                .is_empty();
+            if node.stricken && finished < cutoff && all_done {
+                roots.push(node_id);
+            } else {
+                to_explore.extend(node.children);
+            }
+        }
+        roots.sort();
+        roots
+    }
+
+    pub fn save(&self) { self.try_save().unwrap() }
+
+    pub fn try_save(&self) -> io::Result<()> {
+        trace!("try_save()");
         self.assert_node_consistency();
         let data = serialization::serialize_screen(self);
         if let Some(ref path) = self.work_path {
@@ -1602,12 +3085,13 @@ impl Screen {
             if remove_file(&tmp_path).is_ok() {
                 warn!("removed stale tmp file");
             }
-            let mut f = File::create(&tmp_path).unwrap();
-            f.write_all(&*data).unwrap();
-            f.sync_all().unwrap();
-            rename(tmp_path, path).unwrap();
+            let mut f = File::create(&tmp_path)?;
+            f.write_all(&*data)?;
+            f.sync_all()?;
+            rename(tmp_path, path)?;
             info!("saved work to {}", path);
         }
+        Ok(())
     }
 
     pub fn cleanup(&mut self) {
@@ -1651,6 +3135,46 @@ impl Screen {
         }
     }
 
+    // like add_or_remove_arrow, but the destination becomes blocked until
+    // the start is completed
+    pub fn add_or_remove_blocker(&mut self) {
+        if self.drawing_blocker.is_none() {
+            self.drawing_blocker = self.selected.map(|s| self.resolve_alias(s));
+            return;
+        }
+        let from = self.drawing_blocker.take().unwrap();
+        if let Some(to) = self.selected.map(|s| self.resolve_alias(s)) {
+            if from != to && self.exists(from) && self.exists(to) {
+                let arrow = (from, to);
+                if self.blockers.contains(&arrow) {
+                    self.blockers.retain(|e| e != &arrow);
+                } else {
+                    self.blockers.push(arrow);
+                }
+            }
+        }
+    }
+
+    // tasks waiting on someone else aren't ours to pick up
+    fn is_waiting(&self, node_id: NodeID) -> bool {
+        self.lineage(self.resolve_alias(node_id)).iter().any(|&n| {
+            self.with_node(n, |n| n.content.contains("#waiting="))
+                .unwrap()
+        })
+    }
+
+    // a node is blocked while any blocker of it or its ancestors is open
+    fn is_blocked(&self, node_id: NodeID) -> bool {
+        if self.blockers.is_empty() {
+            return false;
+        }
+        self.lineage(self.resolve_alias(node_id)).iter().any(|&n| {
+            self.blockers.iter().any(|&(from, to)| {
+                to == n && self.with_node(from, |f| !f.stricken).unwrap_or(false)
+            })
+        })
+    }
+
     // NB correctness depends on invariant of the leftmost element being the
     // value in self.drawn_at
     fn bounds_for_lookup(&self, node_id: NodeID) -> Option<(Coords, Coords)> {
@@ -1690,10 +3214,21 @@ impl Screen {
         self.lookup.clear();
         self.drawn_at.clear();
         self.lowest_drawn = 0;
+        self.cache_estimates();
         print!("{}", clear::All);
 
-        // print visible nodes
-        self.draw_children_of_root();
+        // print visible nodes, or the board if we're drilled into one
+        if let Some((_, columns)) = self.kanban() {
+            self.draw_kanban(columns);
+        } else {
+            self.draw_children_of_root();
+        }
+        // a selected entry that wasn't generated again is gone
+        if let Some(selected_id) = self.selected {
+            if selected_id > self.max_id && !self.ephemeral_nodes.contains_key(&selected_id) {
+                self.selected = None;
+            }
+        }
 
         // TODO figure out why header doesn't get shown
         // when a root node is NOT drawn at 1,1
@@ -1723,7 +3258,7 @@ impl Screen {
         }
 
         // print arrows
-        for &(ref from, ref to) in &self.arrows {
+        for &(ref from, ref to) in self.arrows.iter().chain(&self.blockers) {
             let (path, (direction1, direction2)) = self.path_between_nodes(*from, *to);
             self.draw_path(path, direction1, direction2);
         }
@@ -1808,6 +3343,229 @@ impl Screen {
         }
     }
 
+    fn glyph(&self, node: &Node) -> &'static str {
+        // generated entries are aliases too, of the node they list
+        let target = node.alias_of.filter(|&t| self.exists(t)).unwrap_or(node.id);
+        let state = self.state_of(target);
+        if node.stricken && state == "cancelled" {
+            "✗"
+        } else if node.stricken {
+            "☠"
+        } else if self.is_blocked(target) {
+            "⊘"
+        } else if state == "doing" {
+            "▶"
+        } else if state == "waiting" {
+            "◷"
+        } else if state != self.config.states[0] {
+            "◇"
+        } else if node.collapsed {
+            "⊞"
+        } else if node.alias_of.is_some() {
+            "↪"
+        } else if node.hide_stricken {
+            "⚔"
+        } else if node.free_text.is_some() {
+            "✏"
+        } else {
+            " "
+        }
+    }
+
+    // the columns of the board drawn instead of the tree when the drawing
+    // root is tagged with #kanban. plain #kanban uses its children as
+    // columns, #kanban=state the workflow states, and #kanban=<key> the
+    // values of #<key>= on its children
+    fn kanban(&self) -> Option<(Option<String>, Vec<KanbanColumn>)> {
+        lazy_static! {
+            static ref RE_KANBAN: Regex = Regex::new(r"#kanban(?:=(\S+))?").unwrap();
+        }
+        let root = self.with_node(self.drawing_root, |n| n.clone()).unwrap();
+        let dimension = RE_KANBAN
+            .captures(&root.content)?
+            .get(1)
+            .map(|m| m.as_str().to_owned());
+        let shown = |&c: &NodeID| {
+            !root.hide_stricken
+                || !self
+                    .with_node(self.resolve_alias(c), |c| c.stricken)
+                    .unwrap_or(false)
+        };
+
+        let columns = match dimension.as_deref() {
+            None => root
+                .children
+                .iter()
+                .map(|&c| {
+                    let node = self
+                        .with_node(self.resolve_alias(c), |n| n.clone())
+                        .unwrap();
+                    KanbanColumn {
+                        title: node.content.clone(),
+                        node: Some(c),
+                        value: None,
+                        cards: node.children.into_iter().filter(&shown).collect(),
+                    }
+                })
+                .collect(),
+            Some("state") => {
+                let mut columns: Vec<KanbanColumn> = self
+                    .config
+                    .states
+                    .iter()
+                    .map(|s| KanbanColumn {
+                        title: s.clone(),
+                        node: None,
+                        value: Some(s.clone()),
+                        cards: vec![],
+                    })
+                    .collect();
+                for card in root.children.iter().cloned().filter(&shown) {
+                    let state = self.state_of(card);
+                    match columns
+                        .iter()
+                        .position(|c| c.value.as_ref() == Some(&state))
+                    {
+                        Some(idx) => columns[idx].cards.push(card),
+                        None => columns.push(KanbanColumn {
+                            title: state.clone(),
+                            node: None,
+                            value: Some(state),
+                            cards: vec![card],
+                        }),
+                    }
+                }
+                columns
+            },
+            Some(key) => {
+                let re = Regex::new(&format!(r"#{}=(\S+)", regex::escape(key))).unwrap();
+                let mut columns = vec![KanbanColumn {
+                    title: format!("no {}", key),
+                    node: None,
+                    value: None,
+                    cards: vec![],
+                }];
+                for card in root.children.iter().cloned().filter(&shown) {
+                    let content = self
+                        .with_node(self.resolve_alias(card), |n| n.content.clone())
+                        .unwrap();
+                    let value = re_matches::<String>(&re, &content).first().cloned();
+                    match columns.iter().position(|c| c.value == value) {
+                        Some(idx) => columns[idx].cards.push(card),
+                        None => columns.push(KanbanColumn {
+                            title: value.clone().unwrap(),
+                            node: None,
+                            value,
+                            cards: vec![card],
+                        }),
+                    }
+                }
+                columns[1..].sort_by(|a, b| a.value.cmp(&b.value));
+                columns
+            },
+        };
+        Some((dimension, columns))
+    }
+
+    fn draw_kanban(&mut self, columns: Vec<KanbanColumn>) {
+        trace!("draw_kanban()");
+        let width = max(self.dims.0 / max(columns.len(), 1) as u16, 2);
+        for (i, column) in columns.into_iter().enumerate() {
+            let x = 1 + i as u16 * width;
+            match column.node {
+                Some(header) => self.draw_card(header, (x, 2), width - 1),
+                None => {
+                    if let Some((x, y)) = self.internal_to_screen_xy((x, 2)) {
+                        let title: String = UnicodeSegmentation::graphemes(&*column.title, true)
+                            .take(width as usize - 1)
+                            .collect();
+                        print!(
+                            "{}{}{}{}",
+                            cursor::Goto(x, y),
+                            style::Bold,
+                            title,
+                            style::Reset
+                        );
+                    }
+                },
+            }
+            for (j, card) in column.cards.into_iter().enumerate() {
+                self.draw_card(card, (x, 3 + j as u16), width - 1);
+            }
+        }
+    }
+
+    // draws a single line for the node on a kanban board, so that it
+    // can be clicked on and navigated to like nodes in the tree
+    fn draw_card(&mut self, node_id: NodeID, internal_coords: Coords, max_width: u16) {
+        let raw_node = self.with_any_node(node_id, |n| n.clone()).unwrap();
+        let mut node = self.format_node(&raw_node);
+        if raw_node.selected {
+            node.content = raw_node.content.clone();
+        }
+        let text = format!("{}{}", self.glyph(&node), node.content);
+        let text: String = UnicodeSegmentation::graphemes(&*text, true)
+            .take(max_width as usize)
+            .collect();
+        let visible_graphemes = UnicodeSegmentation::graphemes(&*text, true).count();
+
+        if let Some((x, y)) = self.internal_to_screen_xy(internal_coords) {
+            let invert = if node.selected {
+                format!("{}", style::Invert)
+            } else {
+                String::new()
+            };
+            print!("{}{}{}{}", cursor::Goto(x, y), invert, text, style::Reset);
+        }
+
+        self.drawn_at.insert(node_id, internal_coords);
+        for x in internal_coords.0..(internal_coords.0 + visible_graphemes as u16) {
+            self.lookup.insert((x, internal_coords.1), node_id);
+        }
+        if internal_coords.1 > self.lowest_drawn {
+            self.lowest_drawn = internal_coords.1;
+        }
+    }
+
+    // moves the selected card to the next column to the left or right,
+    // by reparenting it or by changing its state or tag
+    fn move_card(&mut self, right: bool) {
+        trace!("move_card({})", right);
+        let selected_id = match self.selected {
+            Some(selected_id) => selected_id,
+            None => return,
+        };
+        let (dimension, columns) = match self.kanban() {
+            Some(kanban) => kanban,
+            None => return,
+        };
+        let from = match columns.iter().position(|c| c.cards.contains(&selected_id)) {
+            Some(from) => from,
+            None => return,
+        };
+        let to = if right {
+            from + 1
+        } else if from > 0 {
+            from - 1
+        } else {
+            return;
+        };
+        let to = match columns.get(to) {
+            Some(to) => to,
+            None => return,
+        };
+
+        let target = self.resolve_alias(selected_id);
+        match dimension.as_deref() {
+            None => {
+                let parent_id = self.resolve_alias(to.node.unwrap());
+                self.reparent(selected_id, parent_id);
+            },
+            Some("state") => self.set_state(target, to.value.as_ref().unwrap()),
+            Some(key) => self.replace_tag(target, key, to.value.as_deref()),
+        }
+    }
+
     // recursively draw node and children, returning how many have been drawn
     fn draw_node(
         &mut self,
@@ -1817,31 +3575,41 @@ impl Screen {
         last: bool,
         hide_stricken: bool,
         color: String,
-    ) -> usize
-    {
+    ) -> usize {
         trace!("draw_node({})", node_id);
-        let mut ephemeral = false;
-        let raw_node = self
-            .nodes
-            .get(&node_id)
-            .or_else(|| {
-                ephemeral = true;
-                self.ephemeral_nodes.get(&node_id)
-            })
-            .cloned()
-            .unwrap();
-        let node = if raw_node.selected {
+        let ephemeral = self.ephemeral_nodes.contains_key(&node_id);
+        let mut raw_node = self.with_any_node(node_id, |n| n.clone()).unwrap();
+        if ephemeral {
+            raw_node.selected = self.selected == Some(node_id);
+        }
+        if raw_node.alias_of.is_some() || ephemeral {
+            // the target may have been edited since we last measured,
+            // and ephemeral ids are reused between draws
+            self.grapheme_cache.remove(&node_id);
+        }
+        let node = if raw_node.selected && !ephemeral {
             let mut formatted = self.format_node(&raw_node);
-            formatted.content = raw_node.content;
+            formatted.content = self
+                .with_node(self.resolve_alias(node_id), |n| n.content.clone())
+                .unwrap();
             formatted
         } else {
             self.format_node(&raw_node)
         };
+        if node.content != raw_node.content {
+            // rolled up estimates and plots change without this node changing
+            self.grapheme_cache.remove(&node_id);
+        }
         if node.stricken && hide_stricken {
             return 0;
         }
 
         let reset = &*format!("{}", color::Fg(color::Reset));
+        let due_color = if node.selected || node.stricken {
+            String::new()
+        } else {
+            due_highlight(node.meta.due)
+        };
         let mut pre_meta = String::new();
         let mut buf = String::new();
 
@@ -1861,23 +3629,13 @@ impl Screen {
                     write!(&mut buf, "├─").unwrap();
                 }
             }
-            if node.stricken {
-                write!(&mut buf, "☠").unwrap();
-            } else if node.collapsed {
-                write!(&mut buf, "⊞").unwrap();
-            } else if node.hide_stricken {
-                write!(&mut buf, "⚔").unwrap();
-            } else if node.free_text.is_some() {
-                write!(&mut buf, "✏").unwrap();
-            } else {
-                write!(&mut buf, " ").unwrap();
-            }
+            write!(&mut buf, "{}", self.glyph(&node)).unwrap();
             // keep color for selected & tree root Fg
             if !node.selected && prefix != "" {
                 write!(&mut buf, "{}", reset).unwrap();
             }
 
-            write!(&mut buf, "{}", node.content).unwrap();
+            write!(&mut buf, "{}{}", due_color, node.content).unwrap();
 
             let max_width = (max(self.dims.0, 1 + x) - 1 - x) as usize;
             let visible_graphemes =
@@ -1885,7 +3643,10 @@ impl Screen {
                     .get(&node.id)
                     .cloned()
                     .unwrap_or_else(|| {
-                        let visible = buf.replace(reset, "").replace(&*pre_meta, "");
+                        let visible = buf
+                            .replace(reset, "")
+                            .replace(&*pre_meta, "")
+                            .replace(&*due_color, "");
                         let vg = UnicodeSegmentation::graphemes(&*visible, true).count();
                         self.grapheme_cache.insert(node.id, vg);
                         vg
@@ -1907,7 +3668,10 @@ impl Screen {
             .get(&node.id)
             .cloned()
             .unwrap_or_else(|| {
-                let visible = buf.replace(reset, "").replace(&*pre_meta, "");
+                let visible = buf
+                    .replace(reset, "")
+                    .replace(&*pre_meta, "")
+                    .replace(&*due_color, "");
                 let vg = UnicodeSegmentation::graphemes(&*visible, true).count();
                 self.grapheme_cache.insert(node.id, vg);
                 vg
@@ -2015,6 +3779,37 @@ impl Screen {
             header_text.push_str(" [auto-arrange] ");
         }
 
+        if let Some(ref context) = self.config.context {
+            write!(&mut header_text, " [@{}] ", context).unwrap();
+        }
+
+        if let Some((node_id, start)) = self.running_timer() {
+            let now = time::get_time().sec as u64;
+            let elapsed = now.saturating_sub(start);
+            let content = self.with_node(node_id, |n| n.content.clone()).unwrap();
+            let label: String = content.chars().take(20).collect();
+            write!(
+                &mut header_text,
+                " [⏱ {} {}:{:02}] ",
+                label,
+                elapsed / 3600,
+                elapsed / 60 % 60
+            )
+            .unwrap();
+        }
+
+        if let Some((_, end)) = self.pomodoro {
+            let now = time::get_time().sec as u64;
+            let remaining = end.saturating_sub(now);
+            write!(
+                &mut header_text,
+                " [pomodoro {}:{:02}] ",
+                remaining / 60,
+                remaining % 60
+            )
+            .unwrap();
+        }
+
         let (plot, finished_today) = self.last_week_of_done_tasks();
         let plot_line = format!("│{}│({} today)", plot, finished_today);
 
@@ -2149,18 +3944,13 @@ impl Screen {
         let now = time::get_time().sec as u64;
         let day_in_sec = 60 * 60 * 24;
         let last_week = now - (day_in_sec * 7);
-        let tasks_finished_in_last_week = self.recursive_child_filter_map(0, &mut |n: &Node| {
-            let f = n.meta.finish_time;
-            if let Some(t) = f {
-                if t > last_week {
-                    Some(t)
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        });
+        let tasks_finished_in_last_week: Vec<u64> = self
+            .recursive_stats_filter_map(0, &mut |n: &Node| Some(n.meta.done_times()))
+            .into_iter()
+            .flatten()
+            .chain(self.archived_done.iter().map(|d| d.time))
+            .filter(|&t| t > last_week)
+            .collect();
         let mut counts = BTreeMap::new();
         for d in 0..7 {
             let t = now - (d * day_in_sec);
@@ -2185,17 +3975,33 @@ impl Screen {
             //// general subtree population and modification
             // limit shows the top N results.
             static ref RE_LIMIT: Regex = Regex::new(r"#limit=(\d+)").unwrap();
+            // query lists the nodes matching the rest of its line, like
+            // #query tag:task and not is:done. see query.rs
+            static ref RE_QUERY: Regex = Regex::new(r"(?m)#query(?:[ \t]+(.*))?$").unwrap();
+            // tagged is sugar for a query clause, ANDed with the others
             static ref RE_TAGGED: Regex = Regex::new(r"#tagged=(\S+)").unwrap();
             static ref RE_REV: Regex = Regex::new(r"#rev\b").unwrap();
             static ref RE_DONE: Regex = Regex::new(r"#done\b").unwrap();
             static ref RE_OPEN: Regex = Regex::new(r"#open\b").unwrap();
+            static ref RE_BLOCKED: Regex = Regex::new(r"#blocked\b").unwrap();
+            static ref RE_READY: Regex = Regex::new(r"#ready\b").unwrap();
+            static ref RE_STATE: Regex = Regex::new(r"#state=(\S+)").unwrap();
+            // agenda lists open tasks with a due date, grouped by urgency
+            static ref RE_AGENDA: Regex = Regex::new(r"#agenda\b").unwrap();
+            // waiting lists open #waiting= tasks grouped by person
+            static ref RE_WAITING: Regex = Regex::new(r"#waiting(?:\s|$)").unwrap();
+            // draws lists auto-task's picks, most recent first
+            static ref RE_DRAWS: Regex = Regex::new(r"#draws\b").unwrap();
             // since defaults to last week
             static ref RE_SINCE: Regex = Regex::new(r"#since=(\S+)").unwrap();
             // until defaults until now
             static ref RE_UNTIL: Regex = Regex::new(r"#until=(\S+)").unwrap();
+            // sort can be {prio,ctime,mtime,finish,due,alpha,done},
+            // prefixed with - for descending order
+            static ref RE_SORT: Regex = Regex::new(r"#sort=(\S+)").unwrap();
 
             //// plot specific
-            // plot can be {new,done}
+            // plot can be {new,done,time,pomodoros,burndown}
             static ref RE_PLOT: Regex = Regex::new(r"#plot=(\S+)").unwrap();
             // n is the number of buckets
             static ref RE_N: Regex = Regex::new(r"#n=(\d+)").unwrap();
@@ -2205,27 +4011,72 @@ impl Screen {
         // NB avoid cycles
         let mut node = raw_node.clone();
 
-        // for tagged queries, AND queries together
-        let mut tagged_children: Option<HashSet<NodeID>> = None;
-        for tag in &re_matches::<String>(&RE_TAGGED, &*node.content) {
-            let children = self.tag_db.tag_to_nodes(tag);
-            if let Some(children_acc) = tagged_children {
-                let children_new = children.into_iter().collect();
-                let intersection = children_acc.intersection(&children_new);
-                tagged_children = Some(intersection.cloned().collect());
-            } else {
-                tagged_children = Some(children.into_iter().collect());
+        // ephemeral nodes are already formatted when they're created
+        if self.ephemeral_nodes.contains_key(&node.id) {
+            return node;
+        }
+
+        // aliases borrow the content and completion state of their target
+        if let Some(target) = raw_node.alias_of {
+            if let Some((content, stricken, due)) =
+                self.with_node(target, |t| (t.content.clone(), t.stricken, t.meta.due))
+            {
+                node.content = content;
+                node.stricken = stricken;
+                node.meta.due = due;
+            }
+        }
+
+        let mut tagged = vec![];
+        let mut errors = vec![];
+        for tag in re_matches::<String>(&RE_TAGGED, &node.content) {
+            match tag_predicate(&tag) {
+                Ok(clause) => tagged.push(clause),
+                Err(e) => errors.push(format!("can't parse #tagged={}: {}", tag, e)),
+            }
+        }
+        let mut clauses = tagged;
+        let listed = match RE_QUERY.captures(&node.content) {
+            Some(cap) => {
+                let text = cap.get(1).map_or("", |m| m.as_str());
+                match blank_directives(text).parse::<Query>() {
+                    Ok(query) => clauses.push(query),
+                    Err(e) => errors.push(format!("can't parse #query {:?}: {}", text, e)),
+                }
+                true
+            },
+            None => false,
+        };
+        for e in errors {
+            if self.query_errors.insert(e.clone()) {
+                warn!("{}", e);
+            }
+        }
+        let query = Query::all(clauses);
+        let queried_nodes = match query {
+            Some(ref query) => self.run_query(query, node.id),
+            None => vec![],
+        };
+        // plots summarize what a query matched, other views list it.
+        // results go through the filters and sorts below as the nodes
+        // they are, and are only swapped for entries after that
+        let mut results = HashSet::new();
+        if listed && !RE_PLOT.is_match(&node.content) {
+            for &result in &queried_nodes {
+                if !node.children.contains(&result) {
+                    results.insert(result);
+                    node.children.push(result);
+                }
             }
         }
-        let queried_nodes = tagged_children
-            .map(|tc| tc.into_iter().collect())
-            .unwrap_or_else(|| vec![]);
 
         let mut since_opt = None;
         let mut until_opt = None;
         if RE_DONE.is_match(&*node.content) {
             for child in node.children.clone() {
-                let done = self.with_node(child, |c| c.stricken).unwrap();
+                let done = self
+                    .with_node(self.resolve_alias(child), |c| c.stricken)
+                    .unwrap();
                 if !done {
                     node.children.retain(|&c| c != child);
                 }
@@ -2233,12 +4084,28 @@ impl Screen {
         }
         if RE_OPEN.is_match(&*node.content) {
             for child in node.children.clone() {
-                let open = self.with_node(child, |c| !c.stricken).unwrap();
+                let open = self
+                    .with_node(self.resolve_alias(child), |c| !c.stricken)
+                    .unwrap();
                 if !open {
                     node.children.retain(|&c| c != child);
                 }
             }
         }
+        if RE_BLOCKED.is_match(&node.content) {
+            node.children.retain(|&c| self.is_blocked(c));
+        }
+        if let Some(state) = re_matches::<String>(&RE_STATE, &node.content).first() {
+            node.children.retain(|&c| self.state_of(c) == *state);
+        }
+        if RE_READY.is_match(&node.content) {
+            node.children.retain(|&c| {
+                let open = self
+                    .with_node(self.resolve_alias(c), |c| !c.stricken)
+                    .unwrap();
+                open && !self.is_blocked(c)
+            });
+        }
         if let Some(since) = re_matches::<String>(&RE_SINCE, &*node.content).get(0) {
             since_opt = dateparse(since.clone());
             if let Some(cutoff) = since_opt {
@@ -2269,12 +4136,49 @@ impl Screen {
                 node.children = new;
             }
         }
+        if RE_DRAWS.is_match(&node.content) {
+            let mut draws = self.draw_history(node.id);
+            node.children.append(&mut draws);
+        }
+        if let Some(&order) = re_matches::<SortOrder>(&RE_SORT, &node.content).first() {
+            node.children = self.sort_children(node.children, order);
+        }
         if RE_REV.is_match(&*node.content) {
             node.children = node.children.into_iter().rev().collect();
         }
         if let Some(&limit) = re_matches(&RE_LIMIT, &*node.content).get(0) {
             node.children.truncate(limit);
         }
+        if !results.is_empty() {
+            let parent_id = node.id;
+            node.children = node
+                .children
+                .into_iter()
+                .map(|c| {
+                    if results.contains(&c) {
+                        self.query_entry(parent_id, c)
+                    } else {
+                        c
+                    }
+                })
+                .collect();
+        }
+
+        if RE_AGENDA.is_match(&node.content) {
+            let mut agenda = self.agenda(node.id);
+            node.children.append(&mut agenda);
+        }
+
+        if RE_WAITING.is_match(&node.content) {
+            let mut waiting = self.waiting(node.id);
+            node.children.append(&mut waiting);
+        }
+
+        if !raw_node.children.is_empty() {
+            if let Some(est) = self.remaining_estimate(node.id).filter(|&est| est > 0) {
+                node.content = format!("{} [est {}]", node.content, format_estimate(est));
+            }
+        }
 
         let re_n = re_matches::<usize>(&RE_N, &*node.content);
         let n_opt = re_n.get(0);
@@ -2285,49 +4189,390 @@ impl Screen {
             let until = until_opt.unwrap_or_else(|| now);
 
             node.content = match plot.as_str() {
-                "done" => self.plot(queried_nodes, PlotType::Done, buckets, since, until),
-                "new" => self.plot(queried_nodes, PlotType::New, buckets, since, until),
+                "done" => self.plot(
+                    queried_nodes,
+                    query.as_ref(),
+                    PlotType::Done,
+                    buckets,
+                    since,
+                    until,
+                ),
+                "new" => self.plot(
+                    queried_nodes,
+                    query.as_ref(),
+                    PlotType::New,
+                    buckets,
+                    since,
+                    until,
+                ),
+                "time" => self.plot_time(queried_nodes, buckets, since, until),
+                "burndown" => self.plot_burndown(queried_nodes, buckets, since, until),
+                "pomodoros" => self.plot(
+                    queried_nodes,
+                    query.as_ref(),
+                    PlotType::Pomodoros,
+                    buckets,
+                    since,
+                    until,
+                ),
                 _ => node.content,
             };
         }
         node
     }
 
+    fn new_ephemeral_node(&mut self, parent_id: NodeID, content: String) -> NodeID {
+        let id = self.ephemeral_max_id;
+        self.ephemeral_max_id -= 1;
+        assert!(self.max_id < self.ephemeral_max_id);
+        let node = Node {
+            id,
+            parent_id,
+            content,
+            ..Node::default()
+        };
+        self.ephemeral_nodes.insert(id, node);
+        id
+    }
+
+    // builds ephemeral group nodes under parent_id for each of overdue,
+    // today, this week and later, each holding aliases of the tasks due then
+    fn agenda(&mut self, parent_id: NodeID) -> Vec<NodeID> {
+        let day_in_sec = 60 * 60 * 24;
+        let today = time::get_time().sec as u64 / day_in_sec;
+        let mut due_tasks: Vec<(u64, NodeID)> = self
+            .open_tasks()
+            .into_iter()
+            .filter_map(|t| self.with_node(t, |n| n.meta.due).unwrap().map(|d| (d, t)))
+            .collect();
+        due_tasks.sort();
+
+        let labels = ["overdue", "today", "this week", "later"];
+        let mut groups = vec![vec![]; labels.len()];
+        for (due, task) in due_tasks {
+            let day = due / day_in_sec;
+            let idx = if day < today {
+                0
+            } else if day == today {
+                1
+            } else if day < today + 7 {
+                2
+            } else {
+                3
+            };
+            groups[idx].push((due, task));
+        }
+
+        let mut agenda = vec![];
+        for (label, tasks) in labels.iter().zip(groups) {
+            if tasks.is_empty() {
+                continue;
+            }
+            let group_id = self.new_ephemeral_node(parent_id, (*label).to_owned());
+            for (due, task) in tasks {
+                let content = self.with_node(task, |n| n.content.clone()).unwrap();
+                let entry = format!("{} {}", format_date(due), content);
+                let entry_id = self.new_ephemeral_node(group_id, entry);
+                let group = self.ephemeral_nodes.get_mut(&group_id).unwrap();
+                group.children.push(entry_id);
+                let entry = self.ephemeral_nodes.get_mut(&entry_id).unwrap();
+                entry.alias_of = Some(task);
+                entry.meta.due = Some(due);
+            }
+            agenda.push(group_id);
+        }
+        agenda
+    }
+
+    // builds an ephemeral group under parent_id for each person open
+    // tasks are #waiting= on, holding aliases of those tasks by their
+    // #followup= date. passed follow-ups are highlighted like due dates
+    fn waiting(&mut self, parent_id: NodeID) -> Vec<NodeID> {
+        let mut groups = vec![];
+        for person in self.tag_db.people() {
+            let mut tasks: Vec<(Option<u64>, NodeID)> = self
+                .tag_db
+                .waiting_on(&person)
+                .into_iter()
+                .filter(|&t| {
+                    self.with_node(t, |n| !n.stricken && !is_template(&n.content))
+                        .unwrap_or(false)
+                })
+                .map(|t| (self.with_node(t, |n| followup(&n.content)).unwrap(), t))
+                .collect();
+            if tasks.is_empty() {
+                continue;
+            }
+            // tasks without a follow-up date go last
+            tasks.sort_by_key(|&(followup, task)| (followup.is_none(), followup, task));
+
+            let group_id = self.new_ephemeral_node(parent_id, person);
+            for (followup, task) in tasks {
+                let content = self.with_node(task, |n| n.content.clone()).unwrap();
+                let entry = match followup {
+                    Some(followup) => format!("{} {}", format_date(followup), content),
+                    None => content,
+                };
+                let entry_id = self.new_ephemeral_node(group_id, entry);
+                let group = self.ephemeral_nodes.get_mut(&group_id).unwrap();
+                group.children.push(entry_id);
+                let entry = self.ephemeral_nodes.get_mut(&entry_id).unwrap();
+                entry.alias_of = Some(task);
+                entry.meta.due = followup;
+            }
+            groups.push(group_id);
+        }
+        groups
+    }
+
+    // every node but the root and `from` itself that matches the query,
+    // leaving out aliases and templates
+    pub fn run_query(&self, query: &Query, from: NodeID) -> Vec<NodeID> {
+        let candidates = match self.query_candidates(query) {
+            Some(candidates) => candidates,
+            None => self.nodes.keys().cloned().collect(),
+        };
+        let mut matched: Vec<NodeID> = candidates
+            .into_iter()
+            .filter_map(|id| self.nodes.get(&id))
+            .filter(|n| n.id != 0 && n.id != from && n.alias_of.is_none())
+            .filter(|n| !is_template(&n.content) && self.query_matches(query, n))
+            .map(|n| n.id)
+            .collect();
+        matched.sort();
+        matched
+    }
+
+    // the only nodes a query can match, looked up in the tag index, or
+    // None when it has no tag that every match needs
+    fn query_candidates(&self, query: &Query) -> Option<Vec<NodeID>> {
+        match *query {
+            Query::Tag(ref tag) => Some(self.tag_db.tag_to_nodes(tag)),
+            Query::Value(ref key, cmp, ref value) => Some(self.tag_db.compared(key, cmp, value)),
+            Query::And(ref a, ref b) => {
+                match (self.query_candidates(a), self.query_candidates(b)) {
+                    (Some(a), Some(b)) if b.len() < a.len() => Some(b),
+                    (Some(a), _) => Some(a),
+                    (None, b) => b,
+                }
+            },
+            Query::Or(ref a, ref b) => {
+                let mut candidates = self.query_candidates(a)?;
+                candidates.append(&mut self.query_candidates(b)?);
+                candidates.sort();
+                candidates.dedup();
+                Some(candidates)
+            },
+            _ => None,
+        }
+    }
+
+    fn query_matches(&self, query: &Query, node: &Node) -> bool {
+        match *query {
+            Query::And(ref a, ref b) => self.query_matches(a, node) && self.query_matches(b, node),
+            Query::Or(ref a, ref b) => self.query_matches(a, node) || self.query_matches(b, node),
+            Query::Not(ref q) => !self.query_matches(q, node),
+            Query::Content(ref text) => node.content.to_lowercase().contains(&text.to_lowercase()),
+            Query::Tag(_) | Query::Value(..) | Query::Like(..) => {
+                self.tag_db.matches(node.id, query)
+            },
+            Query::State(ref state) => self.state_of(node.id) == *state,
+            Query::Open => !node.stricken,
+            Query::Done => node.stricken,
+            Query::Blocked => self.is_blocked(node.id),
+            Query::Date(field, cmp, date) => {
+                let value = match field {
+                    DateField::Ctime => Some(node.meta.ctime),
+                    DateField::Mtime => Some(node.meta.mtime),
+                    DateField::Finish => node.meta.finish_time,
+                    DateField::Due => node.meta.due,
+                };
+                match value {
+                    Some(value) => cmp.holds(value, date),
+                    None => false,
+                }
+            },
+        }
+    }
+
+    // an ephemeral alias of a query result under parent_id, so the
+    // result can be listed without being moved there
+    fn query_entry(&mut self, parent_id: NodeID, result: NodeID) -> NodeID {
+        let (content, stricken, meta) = self
+            .with_node(result, |n| (n.content.clone(), n.stricken, n.meta.clone()))
+            .unwrap();
+        let entry_id = self.new_ephemeral_node(parent_id, content);
+        let entry = self.ephemeral_nodes.get_mut(&entry_id).unwrap();
+        entry.alias_of = Some(result);
+        entry.stricken = stricken;
+        entry.meta = meta;
+        entry_id
+    }
+
+    // an ephemeral entry under parent_id for each draw, most recent
+    // first, pointing at the drawn task if it still exists
+    fn draw_history(&mut self, parent_id: NodeID) -> Vec<NodeID> {
+        let mut entries = vec![];
+        for draw in self.draws.clone().into_iter().rev() {
+            let tm = time::at(time::Timespec::new(draw.time as i64, 0));
+            let when = time::strftime("%Y-%m-%d %H:%M", &tm).unwrap();
+            let outcome = draw
+                .outcome
+                .map_or_else(|| "open".to_owned(), |o| o.to_string());
+            let target = self
+                .nodes
+                .get(&draw.node_id)
+                .map(|n| (n.content.clone(), n.stricken));
+            let content = target.as_ref().map_or("", |t| &t.0);
+            let entry = format!("{} {}: {}", when, outcome, content);
+            let entry_id = self.new_ephemeral_node(parent_id, entry);
+            if let Some((_, stricken)) = target {
+                let entry = self.ephemeral_nodes.get_mut(&entry_id).unwrap();
+                entry.alias_of = Some(draw.node_id);
+                entry.stricken = stricken;
+            }
+            entries.push(entry_id);
+        }
+        entries
+    }
+
+    // jumps to the first #agenda node, creating one under home if needed
+    fn show_agenda(&mut self) {
+        let existing = self
+            .tag_db
+            .tag_to_nodes("agenda")
+            .into_iter()
+            .find(|&n| self.exists(n));
+        let agenda = existing.unwrap_or_else(|| {
+            let agenda = self.new_node();
+            let content = "agenda #agenda".to_owned();
+            self.with_node_mut_no_meta(agenda, |n| {
+                n.parent_id = 0;
+                n.content = content.clone();
+            });
+            self.with_node_mut_no_meta(0, |root| root.children.push(agenda));
+            self.tag_db.reindex(agenda, content);
+            agenda
+        });
+        // jump there without selecting, so typing doesn't edit the query
+        self.zoom_select(agenda);
+        self.unselect();
+    }
+
+    fn plot_time(
+        &self,
+        queried_nodes: Vec<NodeID>,
+        buckets: usize,
+        since: u64,
+        until: u64,
+    ) -> String {
+        let now = time::get_time().sec as u64;
+        let mut spans = vec![];
+        for &c in &queried_nodes {
+            let new = self.recursive_stats_filter_map(c, &mut |n: &Node| {
+                let spans: Vec<(i64, i64)> = n
+                    .meta
+                    .intervals
+                    .iter()
+                    .map(|&(start, end)| (start as i64, end.unwrap_or(now) as i64))
+                    .filter(|&(_, end)| end >= since as i64)
+                    .collect();
+                Some(spans)
+            });
+            spans.extend(new.into_iter().flatten());
+        }
+        let plot = plot::bounded_duration_sparkline(spans, since as i64, until as i64, buckets);
+        format!("|{}|", plot)
+    }
+
+    fn plot_burndown(
+        &self,
+        queried_nodes: Vec<NodeID>,
+        buckets: usize,
+        since: u64,
+        until: u64,
+    ) -> String {
+        let mut items = vec![];
+        for &c in &queried_nodes {
+            // only count the leaves of the estimate roll-up, so that
+            // parents with their own #est= aren't counted twice
+            let new = self.recursive_stats_filter_map(c, &mut |n: &Node| {
+                if n.alias_of.is_some()
+                    || n.children
+                        .iter()
+                        .any(|&c| self.remaining_estimate(c).is_some())
+                {
+                    return None;
+                }
+                let est = estimate(&n.content)?;
+                let finished = if n.stricken { n.meta.finish_time } else { None };
+                Some((
+                    n.meta.ctime as i64,
+                    finished.map_or(i64::MAX, |t| t as i64),
+                    est as i64,
+                ))
+            });
+            items.extend(new);
+        }
+        let plot = plot::bounded_remaining_sparkline(items, since as i64, until as i64, buckets);
+        format!("|{}|", plot)
+    }
+
     fn plot(
         &self,
         queried_nodes: Vec<NodeID>,
+        query: Option<&Query>,
         kind: PlotType,
         buckets: usize,
         since: u64,
         until: u64,
-    ) -> String
-    {
+    ) -> String {
         let mut nodes = vec![];
         for &c in &queried_nodes {
-            let mut new = self.recursive_child_filter_map(c, &mut |n: &Node| match kind {
-                PlotType::Done => {
-                    if let Some(ft) = n.meta.finish_time {
-                        if ft >= since {
-                            return Some(ft as i64);
-                        }
-                    }
-                    None
-                },
+            let new = self.recursive_stats_filter_map(c, &mut |n: &Node| match kind {
+                PlotType::Done => Some(
+                    n.meta
+                        .done_times()
+                        .into_iter()
+                        .filter(|&t| t >= since)
+                        .map(|t| t as i64)
+                        .collect::<Vec<_>>(),
+                ),
                 PlotType::New => {
                     if n.meta.ctime >= since {
-                        Some(n.meta.ctime as i64)
+                        Some(vec![n.meta.ctime as i64])
                     } else {
                         None
                     }
                 },
+                PlotType::Pomodoros => Some(
+                    n.meta
+                        .pomodoros
+                        .iter()
+                        .filter(|&&t| t >= since)
+                        .map(|&t| t as i64)
+                        .collect::<Vec<_>>(),
+                ),
             });
-            nodes.append(&mut new);
+            nodes.extend(new.into_iter().flatten());
+        }
+        if let (PlotType::Done, Some(query)) = (kind, query) {
+            // archived completions count if the query matches them too
+            let archived = self
+                .archived_done
+                .iter()
+                .filter(|d| d.time >= since && d.matches(query))
+                .map(|d| d.time as i64);
+            nodes.extend(archived);
         }
         let plot = plot::bounded_count_sparkline(nodes, since as i64, until as i64, buckets);
         format!("|{}|", plot)
     }
 }
 
+// a node taken out of its parent's children: (node, parent, position)
+type Removal = (NodeID, NodeID, usize);
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 enum SearchDirection {
     Forward,
@@ -2337,6 +4582,891 @@ enum SearchDirection {
 enum PlotType {
     New,
     Done,
+    Pomodoros,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SortField {
+    Prio,
+    Ctime,
+    Mtime,
+    Finish,
+    Due,
+    Alpha,
+    Done,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct SortOrder {
+    field: SortField,
+    descending: bool,
+}
+
+impl FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<SortOrder, String> {
+        let (descending, field) = match s.strip_prefix('-') {
+            Some(field) => (true, field),
+            None => (false, s),
+        };
+        let field = match field {
+            "prio" => SortField::Prio,
+            "ctime" => SortField::Ctime,
+            "mtime" => SortField::Mtime,
+            "finish" => SortField::Finish,
+            "due" => SortField::Due,
+            "alpha" => SortField::Alpha,
+            "done" => SortField::Done,
+            other => return Err(format!("unknown sort field {:?}", other)),
+        };
+        Ok(SortOrder { field, descending })
+    }
+}
+
+struct KanbanColumn {
+    title: String,
+    // the node cards are children of, when columns are nodes
+    node: Option<NodeID>,
+    // the state or tag value cards share, when they aren't
+    value: Option<String>,
+    cards: Vec<NodeID>,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum SortValue {
+    Num(u64),
+    Text(String),
+}
+
+// red for overdue nodes, yellow for ones due today
+fn due_highlight(due: Option<u64>) -> String {
+    let day_in_sec = 60 * 60 * 24;
+    let today = time::get_time().sec as u64 / day_in_sec;
+    match due.map(|d| d / day_in_sec) {
+        Some(day) if day < today => format!("{}", color::Fg(color::Red)),
+        Some(day) if day == today => format!("{}", color::Fg(color::Yellow)),
+        _ => String::new(),
+    }
+}
+
+lazy_static! {
+    static ref RE_DUE: Regex = Regex::new(r"#due=(\S+)").unwrap();
+    static ref RE_FOLLOWUP: Regex = Regex::new(r"#followup=(\S+)").unwrap();
+    static ref RE_TEMPLATE: Regex = Regex::new(r"\s*#template=\S+").unwrap();
+}
+
+fn followup(content: &str) -> Option<u64> {
+    re_matches::<String>(&RE_FOLLOWUP, content)
+        .first()
+        .and_then(|date| parse_due(date))
+}
+
+fn estimate(content: &str) -> Option<u64> {
+    lazy_static! {
+        static ref RE_EST: Regex = Regex::new(r"#est=(\S+)").unwrap();
+    }
+    re_matches::<String>(&RE_EST, content)
+        .first()
+        .and_then(|est| parse_estimate(est))
+}
+
+// the contexts a node asks for, as @home or #ctx=home
+fn contexts(content: &str) -> Vec<String> {
+    lazy_static! {
+        static ref RE_AT: Regex = Regex::new(r"(?:^|\s)@([\w-]+)").unwrap();
+        static ref RE_CTX: Regex = Regex::new(r"#ctx=(\S+)").unwrap();
+    }
+    let mut contexts = re_matches::<String>(&RE_AT, content);
+    contexts.append(&mut re_matches::<String>(&RE_CTX, content));
+    contexts
+}
+
+// view directives can share a line with #query, so they're blanked out
+// of the query, keeping the columns errors point at
+fn blank_directives(text: &str) -> String {
+    lazy_static! {
+        static ref RE_WORD: Regex = Regex::new(r"\S+").unwrap();
+    }
+    let flags = [
+        "#rev", "#done", "#open", "#blocked", "#ready", "#agenda", "#waiting", "#draws", "#kanban",
+    ];
+    let settings = [
+        "#limit=", "#tagged=", "#state=", "#since=", "#until=", "#sort=", "#plot=", "#n=",
+        "#kanban=",
+    ];
+    RE_WORD
+        .replace_all(text, |cap: &Captures| {
+            let word = &cap[0];
+            if flags.contains(&word) || settings.iter().any(|s| word.starts_with(s)) {
+                " ".repeat(word.chars().count())
+            } else {
+                word.to_owned()
+            }
+        })
+        .into_owned()
 }
 
+fn is_template(content: &str) -> bool { RE_TEMPLATE.is_match(content) }
+
 fn visible(view_y: u16, height: u16, y: u16) -> bool { y > view_y && y < view_y + height }
+
+#[test]
+fn test_undo_delete_restores_aliases() {
+    let mut screen = Screen {
+        is_test: true,
+        ..Screen::default()
+    };
+    let project = screen.new_child_node(0, "project".to_owned());
+    let target = screen.new_child_node(project, "target".to_owned());
+    let child = screen.new_child_node(target, "child #tag".to_owned());
+    let elsewhere = screen.new_child_node(0, "elsewhere".to_owned());
+    let before = screen.new_child_node(elsewhere, "before".to_owned());
+    let mut aliases = vec![];
+    for &(parent, of) in &[(elsewhere, target), (elsewhere, child), (project, child)] {
+        let alias = screen.new_child_node(parent, String::new());
+        screen.with_node_mut_no_meta(alias, |n| n.alias_of = Some(of));
+        aliases.push(alias);
+    }
+    let after = screen.new_child_node(elsewhere, "after".to_owned());
+    let children = |screen: &Screen, id| screen.with_node(id, |n| n.children.clone()).unwrap();
+
+    screen.select_node(target);
+    screen.delete_selected(false);
+    assert!(aliases.iter().all(|&alias| !screen.exists(alias)));
+    assert_eq!(children(&screen, elsewhere), vec![before, after]);
+    assert!(children(&screen, project).is_empty());
+
+    screen.undo_delete();
+    assert!(aliases.iter().all(|&alias| screen.exists(alias)));
+    assert_eq!(
+        children(&screen, elsewhere),
+        vec![before, aliases[0], aliases[1], after]
+    );
+    assert_eq!(children(&screen, project), vec![target, aliases[2]]);
+    assert!(screen.undo_nodes.is_empty());
+}
+
+#[test]
+fn test_alias_paste() {
+    let mut screen = Screen::default();
+    let project = screen.new_child_node(0, "project".to_owned());
+    let target = screen.new_child_node(project, "target".to_owned());
+    let elsewhere = screen.new_child_node(0, "elsewhere".to_owned());
+    let before = screen.new_child_node(elsewhere, "before".to_owned());
+    let children = |screen: &Screen, id| screen.with_node(id, |n| n.children.clone()).unwrap();
+    let paste = |screen: &mut Screen, target, parent| {
+        screen.select_node(target);
+        screen.alias_paste();
+        screen.select_node(parent);
+        screen.alias_paste();
+    };
+
+    // a node can't be aliased into itself or anything beneath it
+    paste(&mut screen, target, target);
+    assert!(children(&screen, target).is_empty());
+    paste(&mut screen, project, target);
+    assert!(children(&screen, target).is_empty());
+    assert_eq!(children(&screen, project), vec![target]);
+
+    paste(&mut screen, target, elsewhere);
+    let alias = children(&screen, elsewhere)[1];
+    assert_eq!(screen.with_node(alias, |n| n.alias_of), Some(Some(target)));
+    assert_eq!(screen.selected, Some(alias));
+    let after = screen.new_child_node(elsewhere, "after".to_owned());
+
+    // new children go to the target
+    screen.create_child();
+    let child = screen.selected.unwrap();
+    assert_eq!(children(&screen, target), vec![child]);
+    assert!(children(&screen, alias).is_empty());
+
+    // the alias isn't empty just because its own content is
+    screen.select_node(alias);
+    screen.create_sibling();
+    let sibling = screen.selected.unwrap();
+    assert_eq!(
+        children(&screen, elsewhere),
+        vec![before, alias, sibling, after]
+    );
+}
+
+#[test]
+fn test_blank_directives() {
+    assert_eq!(
+        blank_directives("tag:task #limit=5 #rev or #waiting=bob"),
+        format!("tag:task {} or #waiting=bob", " ".repeat(13))
+    );
+    assert_eq!(blank_directives("#done #sort=-due").trim(), "");
+    assert_eq!(
+        blank_directives("#cat #plot=done )").parse::<Query>(),
+        Err("unmatched ')' at column 17".to_owned())
+    );
+}
+
+#[test]
+fn test_run_query() {
+    let mut screen = Screen::default();
+    let cat = screen.new_child_node(0, "tom #cat #age=3".to_owned());
+    let dog = screen.new_child_node(0, "rex #dog".to_owned());
+    let plain = screen.new_child_node(0, "just a cat".to_owned());
+    let q = |s: &str| s.parse::<Query>().unwrap();
+
+    assert_eq!(screen.query_candidates(&q("#cat")), Some(vec![cat]));
+    assert_eq!(
+        screen.query_candidates(&q("#cat or #dog")),
+        Some(vec![cat, dog])
+    );
+    assert_eq!(screen.query_candidates(&q("#cat or cat")), None);
+    assert_eq!(
+        screen.query_candidates(&q("not #dog and #cat")),
+        Some(vec![cat])
+    );
+    assert_eq!(screen.query_candidates(&q("#age>=2")), Some(vec![cat]));
+    assert_eq!(
+        screen.query_candidates(&q("#age>=2 or #dog")),
+        Some(vec![cat, dog])
+    );
+    assert!(screen.run_query(&q("#age>3"), 0).is_empty());
+    assert_eq!(screen.run_query(&q("#cat or #dog"), 0), vec![cat, dog]);
+    assert_eq!(screen.run_query(&q("cat"), 0), vec![cat, plain]);
+    assert!(screen.run_query(&q("#cat and not #age=3"), 0).is_empty());
+    assert!(screen.run_query(&q("#dog"), dog).is_empty());
+}
+
+#[test]
+fn test_query_filters_and_sorts() {
+    let mut screen = Screen::default();
+    let b = screen.new_child_node(0, "bravo #cat".to_owned());
+    let a = screen.new_child_node(0, "alpha #cat".to_owned());
+    let c = screen.new_child_node(0, "charlie #cat".to_owned());
+    let cats = screen.new_child_node(0, "cats".to_owned());
+    screen.toggle_node_stricken(c);
+    screen.with_node_mut_no_meta(a, |n| n.state = Some("doing".to_owned()));
+    screen.with_node_mut_no_meta(b, |n| n.meta.mtime = 0);
+    screen.blockers.push((b, a));
+    let listed = |screen: &mut Screen, directives: &str| -> Vec<NodeID> {
+        let mut raw = screen.with_node(cats, |n| n.clone()).unwrap();
+        raw.content = format!("cats {} #query #cat", directives);
+        let children = screen.format_node(&raw).children;
+        // every result is listed through an entry
+        children
+            .into_iter()
+            .map(|c| screen.with_any_node(c, |n| n.alias_of).unwrap().unwrap())
+            .collect()
+    };
+
+    assert_eq!(listed(&mut screen, ""), vec![b, a, c]);
+    assert_eq!(listed(&mut screen, "#open"), vec![b, a]);
+    assert_eq!(listed(&mut screen, "#done"), vec![c]);
+    assert_eq!(listed(&mut screen, "#ready"), vec![b]);
+    assert_eq!(listed(&mut screen, "#blocked"), vec![a]);
+    assert_eq!(listed(&mut screen, "#state=doing"), vec![a]);
+    assert_eq!(listed(&mut screen, "#since=1d"), vec![a, c]);
+    assert_eq!(listed(&mut screen, "#until=1d"), vec![b]);
+    assert_eq!(listed(&mut screen, "#sort=alpha"), vec![a, b, c]);
+    assert_eq!(listed(&mut screen, "#sort=-alpha #limit=2"), vec![c, b]);
+
+    screen.with_node_mut_no_meta(cats, |n| n.content = "cats #query #cat".to_owned());
+    screen.arrange();
+    screen.select_node(cats);
+    screen.delete_selected(true);
+    assert!(!screen.exists(cats));
+    assert!(screen.exists(a));
+}
+
+#[test]
+fn test_archive_keeps_nodes_when_write_fails() {
+    let mut screen = Screen {
+        is_test: true,
+        work_path: Some("/nonexistent/void/db".to_owned()),
+        ..Screen::default()
+    };
+    let task = screen.new_child_node(0, "task".to_owned());
+    let sub = screen.new_child_node(task, "sub".to_owned());
+    for &id in &[sub, task] {
+        screen.with_node_mut(id, |n| {
+            n.stricken = true;
+            n.meta.finish_time = Some(1);
+        });
+    }
+
+    assert!(screen.archive_stricken(0, u64::MAX).is_err());
+    assert!(screen.exists(task) && screen.exists(sub));
+    assert_eq!(
+        screen.with_node(0, |n| n.children.clone()),
+        Some(vec![task])
+    );
+    assert!(screen.archived_done.is_empty());
+}
+
+#[test]
+fn test_archive_leaves_draws_open() {
+    let dir = std::env::temp_dir().join(format!("void-archive-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let work_path = dir.join("db").to_str().unwrap().to_owned();
+    let mut screen = Screen {
+        is_test: true,
+        work_path: Some(work_path.clone()),
+        ..Screen::default()
+    };
+    let task = screen.new_child_node(0, "task".to_owned());
+    screen.with_node_mut(task, |n| {
+        n.stricken = true;
+        n.meta.finish_time = Some(1);
+    });
+    screen.draws.push(Draw {
+        node_id: task,
+        time: time::get_time().sec as u64,
+        outcome: None,
+    });
+
+    let archived = screen.archive_stricken(0, u64::MAX);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(archived.unwrap(), 1);
+    assert!(!screen.exists(task));
+    assert_eq!(screen.draws[0].outcome, None);
+}
+
+#[test]
+fn test_agenda_entries_are_navigable() {
+    let mut screen = Screen {
+        is_test: true,
+        dims: (80, 40),
+        ..Screen::default()
+    };
+    let agenda = screen.new_child_node(0, "agenda #agenda".to_owned());
+    let project = screen.new_child_node(0, "project #task".to_owned());
+    screen.with_node_mut_no_meta(agenda, |n| n.rooted_coords = (1, 2));
+    screen.with_node_mut_no_meta(project, |n| n.rooted_coords = (1, 20));
+    let now = time::get_time().sec as u64;
+    let mut tasks = vec![];
+    for &(content, days) in &[("first", 20), ("second", 30)] {
+        let task = screen.new_child_node(project, content.to_owned());
+        screen.with_node_mut_no_meta(task, |n| n.meta.due = Some(now + days * 60 * 60 * 24));
+        tasks.push(task);
+    }
+    screen.draw();
+    let mut drawn: Vec<(u16, NodeID)> = screen
+        .drawn_at
+        .iter()
+        .filter(|&(&id, _)| id > screen.max_id)
+        .map(|(&id, &(_, y))| (y, id))
+        .collect();
+    drawn.sort();
+    // the "later" group, then an entry for each task
+    assert_eq!(drawn.len(), 3);
+    let ids: Vec<NodeID> = drawn.into_iter().map(|(_, id)| id).collect();
+    assert!(screen.with_node(ids[1], |_| ()).is_none());
+
+    screen.select_node(ids[1]);
+    screen.draw();
+    screen.select_down();
+    assert_eq!(screen.selected, Some(ids[2]));
+    screen.select_up();
+    assert_eq!(screen.selected, Some(ids[1]));
+
+    screen.handle_event(Event::Key(Key::Char('\n')));
+    assert_eq!(screen.selected, Some(tasks[0]));
+}
+
+#[test]
+fn test_arrange_and_delete_agenda() {
+    let mut screen = Screen {
+        is_test: true,
+        dims: (80, 40),
+        ..Screen::default()
+    };
+    let agenda = screen.new_child_node(0, "agenda #agenda".to_owned());
+    let project = screen.new_child_node(0, "project #task".to_owned());
+    let task = screen.new_child_node(project, "task".to_owned());
+    let now = time::get_time().sec as u64;
+    screen.with_node_mut_no_meta(task, |n| n.meta.due = Some(now));
+    screen.arrange();
+    screen.draw();
+
+    screen.select_node(agenda);
+    screen.delete_selected(true);
+    assert!(!screen.exists(agenda));
+    assert!(screen.exists(task));
+}
+
+#[test]
+fn test_arrange_and_delete_waiting() {
+    let mut screen = Screen {
+        is_test: true,
+        dims: (80, 40),
+        ..Screen::default()
+    };
+    let waiting = screen.new_child_node(0, "waiting on #waiting".to_owned());
+    let task = screen.new_child_node(0, "report #waiting=bob #followup=1d".to_owned());
+    screen.arrange();
+    screen.draw();
+    assert!(screen.drawn_at.keys().any(|&id| id > screen.max_id));
+
+    screen.select_node(waiting);
+    screen.delete_selected(true);
+    assert!(!screen.exists(waiting));
+    assert!(screen.exists(task));
+}
+
+#[test]
+fn test_instantiate_template() {
+    let mut screen = Screen {
+        is_test: true,
+        ..Screen::default()
+    };
+    let template = screen.new_child_node(0, "trip to {prompt:city} #template=trip".to_owned());
+    let step = screen.new_child_node(template, "book hotel on {date} #due=3d".to_owned());
+    screen.update_due(step);
+    assert_eq!(screen.with_node(step, |n| n.meta.due), Some(None));
+    assert!(screen
+        .with_node(step, |n| n.content.ends_with("#due=3d"))
+        .unwrap());
+    // a later template with the same name isn't the one used
+    screen.new_child_node(0, "other #template=trip".to_owned());
+    let dest = screen.new_child_node(0, "trips".to_owned());
+
+    screen.select_node(dest);
+    screen.instantiate_template("trip");
+    let copies = screen.with_node(dest, |n| n.children.clone()).unwrap();
+    assert_eq!(copies.len(), 1);
+    let copy = screen.with_node(copies[0], |n| n.clone()).unwrap();
+    // prompts can't be answered in tests, so they're left as they were
+    assert_eq!(copy.content, "trip to {prompt:city}");
+    assert_eq!(screen.tag_db.tag_to_nodes("template=trip").len(), 2);
+
+    let today = time::strftime("%Y-%m-%d", &time::now()).unwrap();
+    let copied_step = screen.with_node(copy.children[0], |n| n.clone()).unwrap();
+    let due = parse_due("3d").unwrap();
+    assert_eq!(copied_step.meta.due, Some(due));
+    assert_eq!(
+        copied_step.content,
+        format!("book hotel on {} #due={}", today, format_date(due))
+    );
+}
+
+#[test]
+fn test_import_todotxt() {
+    let mut screen = Screen::default();
+    let project = screen.new_child_node(0, "home #task".to_owned());
+    let task = screen.new_child_node(project, "#prio=3 call @phone mom".to_owned());
+    screen.with_node_mut_no_meta(task, |n| {
+        n.meta.ctime = 1_000_000;
+        n.meta.mtime = 1_000_000;
+    });
+    let other = screen.new_child_node(project, "water plants".to_owned());
+
+    // an unchanged export leaves the node alone
+    let export = crate::todotxt(&screen);
+    assert_eq!(screen.import_todotxt(&export), (2, 0));
+    let node = screen.with_node(task, |n| n.clone()).unwrap();
+    assert_eq!(node.content, "#prio=3 call @phone mom");
+    assert_eq!(node.meta.mtime, 1_000_000);
+
+    let line = format!("(C) 1970-01-12 call mom later @phone +home void:{}", task);
+    assert_eq!(screen.import_todotxt(&line), (1, 0));
+    assert_eq!(
+        screen.with_node(task, |n| n.content.clone()).unwrap(),
+        "call mom later @phone #prio=3"
+    );
+    assert!(screen.fits_context(task, "phone"));
+    assert!(!screen.fits_context(task, "office"));
+
+    // an id from another db doesn't name this one's node
+    let line = format!("2020-05-05 buy milk +home void:{}", other);
+    assert_eq!(screen.import_todotxt(&line), (0, 1));
+    assert_eq!(
+        screen.with_node(other, |n| n.content.clone()).unwrap(),
+        "water plants"
+    );
+}
+
+#[test]
+fn test_undo_delete_reopens_draws() {
+    let mut screen = Screen::default();
+    let task = screen.new_child_node(0, "task".to_owned());
+    let sub = screen.new_child_node(task, "sub".to_owned());
+    let now = time::get_time().sec as u64;
+    for &node_id in &[task, sub] {
+        screen.draws.push(Draw {
+            node_id,
+            time: now,
+            outcome: None,
+        });
+    }
+    screen.select_node(task);
+    screen.delete_selected(false);
+    assert!(screen
+        .draws
+        .iter()
+        .all(|d| d.outcome == Some(DrawOutcome::Deleted)));
+
+    screen.undo_delete();
+    assert!(screen.draws.iter().all(|d| d.outcome.is_none()));
+}
+
+#[test]
+fn test_arrange_and_delete_draws() {
+    let mut screen = Screen {
+        is_test: true,
+        dims: (80, 40),
+        ..Screen::default()
+    };
+    let history = screen.new_child_node(0, "history #draws".to_owned());
+    let task = screen.new_child_node(0, "task".to_owned());
+    let now = time::get_time().sec as u64;
+    // the second draw's node is long gone
+    for &node_id in &[task, task + 100] {
+        screen.draws.push(Draw {
+            node_id,
+            time: now,
+            outcome: None,
+        });
+    }
+    screen.arrange();
+    screen.draw();
+
+    screen.select_node(history);
+    screen.delete_selected(true);
+    assert!(!screen.exists(history));
+    assert!(screen.exists(task));
+    assert!(screen.draws.iter().all(|d| d.outcome.is_none()));
+}
+
+#[test]
+fn test_propagate_completion() {
+    let mut screen = Screen::default();
+    let stricken = |screen: &Screen, id| screen.with_node(id, |n| n.stricken).unwrap();
+    let toggle = |screen: &mut Screen, id| {
+        screen.select_node(id);
+        screen.toggle_stricken();
+    };
+
+    // off unless asked for
+    let plain = screen.new_child_node(0, "plain".to_owned());
+    let only = screen.new_child_node(plain, "only".to_owned());
+    toggle(&mut screen, only);
+    assert!(!stricken(&screen, plain));
+
+    let project = screen.new_child_node(0, "project #autocomplete".to_owned());
+    let parent = screen.new_child_node(project, "parent".to_owned());
+    let a = screen.new_child_node(parent, "a".to_owned());
+    let b = screen.new_child_node(parent, "b".to_owned());
+    toggle(&mut screen, a);
+    assert!(!stricken(&screen, parent));
+    toggle(&mut screen, b);
+    assert!(stricken(&screen, parent));
+    assert!(stricken(&screen, project));
+
+    // reopening a child reopens everything it had closed
+    toggle(&mut screen, a);
+    assert!(!stricken(&screen, parent));
+    assert!(!stricken(&screen, project));
+    assert!(stricken(&screen, b));
+
+    // AUTOCOMPLETE turns it on everywhere, for the next strike of only
+    screen.config.autocomplete = true;
+    toggle(&mut screen, only);
+    assert!(!stricken(&screen, plain));
+    toggle(&mut screen, only);
+    assert!(stricken(&screen, plain));
+}
+
+#[test]
+fn test_task_states() {
+    let mut screen = Screen::default();
+    screen.config.states = ["todo", "doing", "waiting", "done", "cancelled"]
+        .iter()
+        .map(|s| (*s).to_owned())
+        .collect();
+    let task = screen.new_child_node(0, "task".to_owned());
+    screen.select_node(task);
+    let mut seen = vec![];
+    for _ in 0..5 {
+        screen.cycle_state();
+        let (stricken, finished) = screen
+            .with_node(task, |n| (n.stricken, n.meta.finish_time.is_some()))
+            .unwrap();
+        seen.push((screen.state_of(task), stricken, finished));
+    }
+    let expected = [
+        ("doing", false, false),
+        ("waiting", false, false),
+        ("done", true, true),
+        ("cancelled", true, false),
+        ("todo", false, false),
+    ];
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|&(state, stricken, finished)| (state.to_owned(), stricken, finished))
+        .collect();
+    assert_eq!(seen, expected);
+
+    // cancelling isn't completing, even for a recurring task
+    let now = time::get_time().sec as u64;
+    let chore = screen.new_child_node(0, "water plants #every=1d".to_owned());
+    screen.draws.push(Draw {
+        node_id: chore,
+        time: now,
+        outcome: None,
+    });
+    screen.set_state(chore, "cancelled");
+    let node = screen.with_node(chore, |n| n.clone()).unwrap();
+    assert!(node.stricken);
+    assert!(node.meta.done_times().is_empty());
+    assert_eq!(screen.draws[0].outcome, None);
+    assert_eq!(screen.last_week_of_done_tasks().1, 0);
+
+    screen.set_state(chore, "todo");
+    screen.set_state(chore, "done");
+    let node = screen.with_node(chore, |n| n.clone()).unwrap();
+    assert!(!node.stricken);
+    assert_eq!(node.meta.completions.len(), 1);
+    assert_eq!(screen.draws[0].outcome, Some(DrawOutcome::Completed));
+    assert_eq!(screen.last_week_of_done_tasks().1, 1);
+}
+
+#[test]
+fn test_entry_glyphs() {
+    let mut screen = Screen {
+        is_test: true,
+        dims: (80, 40),
+        ..Screen::default()
+    };
+    let agenda = screen.new_child_node(0, "agenda #agenda".to_owned());
+    let project = screen.new_child_node(0, "project #task".to_owned());
+    screen.with_node_mut_no_meta(agenda, |n| n.rooted_coords = (1, 2));
+    screen.with_node_mut_no_meta(project, |n| n.rooted_coords = (1, 20));
+    let now = time::get_time().sec as u64;
+    let mut tasks = vec![];
+    for &content in &["open", "doing", "waiting"] {
+        let task = screen.new_child_node(project, content.to_owned());
+        screen.with_node_mut_no_meta(task, |n| n.meta.due = Some(now));
+        tasks.push(task);
+    }
+    screen.set_state(tasks[1], "doing");
+    screen.set_state(tasks[2], "waiting");
+    screen.draw();
+
+    let glyph = |screen: &Screen, alias_of: Option<NodeID>| {
+        let entry = screen
+            .ephemeral_nodes
+            .values()
+            .find(|n| n.alias_of == alias_of && screen.drawn_at.contains_key(&n.id))
+            .unwrap();
+        screen.glyph(entry)
+    };
+    // the "today" group
+    assert_eq!(glyph(&screen, None), " ");
+    assert_eq!(glyph(&screen, Some(tasks[0])), "↪");
+    assert_eq!(glyph(&screen, Some(tasks[1])), "▶");
+    assert_eq!(glyph(&screen, Some(tasks[2])), "◷");
+}
+
+#[test]
+fn test_blockers() {
+    let mut screen = Screen::default();
+    let list = screen.new_child_node(0, "list".to_owned());
+    let project = screen.new_child_node(list, "project".to_owned());
+    let step = screen.new_child_node(project, "step".to_owned());
+    let blocker = screen.new_child_node(list, "blocker".to_owned());
+    let toggle_blocker = |screen: &mut Screen| {
+        screen.select_node(blocker);
+        screen.add_or_remove_blocker();
+        screen.select_node(project);
+        screen.add_or_remove_blocker();
+    };
+    let listed = |screen: &mut Screen, filter: &str| {
+        let mut raw = screen.with_node(list, |n| n.clone()).unwrap();
+        raw.content = format!("list {}", filter);
+        screen.format_node(&raw).children
+    };
+
+    toggle_blocker(&mut screen);
+    assert_eq!(screen.blockers, vec![(blocker, project)]);
+    assert!(screen.is_blocked(project));
+    assert!(screen.is_blocked(step));
+    assert!(!screen.is_blocked(blocker));
+    assert_eq!(listed(&mut screen, "#blocked"), vec![project]);
+    assert_eq!(listed(&mut screen, "#ready"), vec![blocker]);
+
+    // a finished blocker is out of the way
+    screen.toggle_node_stricken(blocker);
+    assert!(!screen.is_blocked(step));
+    assert!(listed(&mut screen, "#blocked").is_empty());
+    assert_eq!(listed(&mut screen, "#ready"), vec![project]);
+
+    screen.toggle_node_stricken(blocker);
+    assert!(screen.is_blocked(step));
+    toggle_blocker(&mut screen);
+    assert!(screen.blockers.is_empty());
+    assert!(!screen.is_blocked(step));
+}
+
+#[test]
+fn test_move_card() {
+    let mut screen = Screen::default();
+    screen.config.states = ["todo", "doing", "done"]
+        .iter()
+        .map(|s| (*s).to_owned())
+        .collect();
+    let content = |screen: &Screen, id| screen.with_node(id, |n| n.content.clone()).unwrap();
+    let move_card = |screen: &mut Screen, board, card, right| {
+        screen.drawing_root = board;
+        screen.select_node(card);
+        screen.move_card(right);
+    };
+
+    let board = screen.new_child_node(0, "states #kanban=state".to_owned());
+    let card = screen.new_child_node(board, "card".to_owned());
+    move_card(&mut screen, board, card, true);
+    assert_eq!(screen.state_of(card), "doing");
+    move_card(&mut screen, board, card, false);
+    assert_eq!(screen.state_of(card), "todo");
+
+    let board = screen.new_child_node(0, "stages #kanban=stage".to_owned());
+    let staged = screen.new_child_node(board, "a #stage=1".to_owned());
+    screen.new_child_node(board, "b #stage=2".to_owned());
+    let unstaged = screen.new_child_node(board, "c".to_owned());
+    move_card(&mut screen, board, unstaged, true);
+    assert_eq!(content(&screen, unstaged), "c #stage=1");
+    move_card(&mut screen, board, staged, true);
+    assert_eq!(content(&screen, staged), "a #stage=2");
+    move_card(&mut screen, board, unstaged, false);
+    assert_eq!(content(&screen, unstaged), "c");
+
+    let board = screen.new_child_node(0, "columns #kanban".to_owned());
+    let todo = screen.new_child_node(board, "todo".to_owned());
+    let doing = screen.new_child_node(board, "doing".to_owned());
+    let card = screen.new_child_node(todo, "card".to_owned());
+    move_card(&mut screen, board, card, true);
+    assert_eq!(screen.parent(card), Some(doing));
+    // there's nothing further left
+    move_card(&mut screen, board, card, false);
+    move_card(&mut screen, board, card, false);
+    assert_eq!(screen.parent(card), Some(todo));
+}
+
+#[test]
+fn test_review() {
+    let mut screen = Screen::default();
+    screen.config.review_days = 7;
+    let day_in_sec = 60 * 60 * 24;
+    let now = time::get_time().sec as u64;
+    let project = screen.new_child_node(0, "project #task".to_owned());
+    let stale = screen.new_child_node(project, "stale".to_owned());
+    let fresh = screen.new_child_node(project, "fresh".to_owned());
+    let overdue = screen.new_child_node(project, "overdue".to_owned());
+    screen.with_node_mut_no_meta(stale, |n| n.meta.mtime = now - 30 * day_in_sec);
+    screen.with_node_mut_no_meta(fresh, |n| n.meta.mtime = now - 2 * day_in_sec);
+    screen.with_node_mut_no_meta(overdue, |n| n.meta.due = Some(now - 2 * day_in_sec));
+
+    assert_eq!(
+        screen.review_items(),
+        vec![
+            (project, "no priority".to_owned()),
+            (stale, "untouched for 30d".to_owned()),
+            (overdue, "overdue".to_owned()),
+        ]
+    );
+
+    assert!(screen.review_answer(project, 'p', "3"));
+    assert!(!screen.review_answer(stale, 'p', "high"));
+    assert!(screen.review_answer(stale, 'k', ""));
+    assert!(!screen.review_answer(overdue, 'd', "someday"));
+    assert!(screen.review_answer(overdue, 'd', "3d"));
+    assert!(screen.review_items().is_empty());
+    assert_eq!(
+        screen.with_node(project, |n| n.content.clone()).unwrap(),
+        "project #task #prio=3"
+    );
+    assert_eq!(
+        screen.with_node(overdue, |n| n.meta.due).unwrap(),
+        parse_due("3d")
+    );
+
+    assert!(screen.review_answer(project, 'p', ""));
+    assert_eq!(
+        screen.review_items(),
+        vec![(project, "no priority".to_owned())]
+    );
+    assert!(screen.review_answer(stale, 'c', ""));
+    assert!(screen.with_node(stale, |n| n.stricken).unwrap());
+    assert!(screen.review_answer(fresh, 'x', ""));
+    assert!(!screen.exists(fresh));
+}
+
+#[test]
+fn test_pomodoro_answers() {
+    let mut screen = Screen {
+        is_test: true,
+        ..Screen::default()
+    };
+    let project = screen.new_child_node(0, "project #task".to_owned());
+    let task = screen.new_child_node(project, "task".to_owned());
+    let other = screen.new_child_node(project, "other".to_owned());
+    let pomodoros = |screen: &Screen, id| screen.with_node(id, |n| n.meta.pomodoros.len()).unwrap();
+
+    screen.start_pomodoro();
+    let (picked, _) = screen.pomodoro.unwrap();
+    screen.pomodoro = None;
+    screen.pomodoro_answer(picked, 'n');
+    assert_eq!(pomodoros(&screen, picked), 1);
+    assert!(!screen.with_node(picked, |n| n.stricken).unwrap());
+
+    // skipping draws the next task and starts over on it
+    screen.pomodoro_answer(picked, 's');
+    assert_eq!(screen.with_node(picked, |n| n.meta.skipped), Some(1));
+    assert_eq!(screen.draws.len(), 2);
+    assert_eq!(screen.draws[0].outcome, Some(DrawOutcome::Skipped));
+    let (next, end) = screen.pomodoro.unwrap();
+    assert_eq!(screen.draws[1].node_id, next);
+    assert!(end > time::get_time().sec as u64);
+
+    screen.pomodoro = None;
+    screen.pomodoro_answer(task, 'd');
+    screen.pomodoro_answer(other, 'd');
+    assert!(screen.with_node(task, |n| n.stricken).unwrap());
+    assert!(screen.with_node(other, |n| n.stricken).unwrap());
+    assert_eq!(pomodoros(&screen, task) + pomodoros(&screen, other), 3);
+}
+
+#[test]
+fn test_sort_children() {
+    let mut screen = Screen::default();
+    let a = screen.new_child_node(0, "#prio=1 Bravo".to_owned());
+    let b = screen.new_child_node(0, "alpha #prio=3".to_owned());
+    let c = screen.new_child_node(0, "charlie".to_owned());
+    let times = [
+        (a, 30, Some(5), Some(20)),
+        (b, 10, None, Some(10)),
+        (c, 20, Some(7), None),
+    ];
+    for &(id, t, finish, due) in &times {
+        screen.with_node_mut_no_meta(id, |n| {
+            n.meta.ctime = t;
+            n.meta.mtime = 100 - t;
+            n.meta.finish_time = finish;
+            n.stricken = finish.is_some();
+            n.meta.due = due;
+        });
+    }
+    let sorted = |order: &str| screen.sort_children(vec![a, b, c], order.parse().unwrap());
+
+    assert_eq!(sorted("alpha"), vec![b, a, c]);
+    assert_eq!(sorted("-alpha"), vec![c, a, b]);
+    assert_eq!(sorted("ctime"), vec![b, c, a]);
+    assert_eq!(sorted("-ctime"), vec![a, c, b]);
+    assert_eq!(sorted("mtime"), vec![a, c, b]);
+    assert_eq!(sorted("done"), vec![b, a, c]);
+    assert_eq!(sorted("-done"), vec![a, c, b]);
+    // missing values go last either way
+    assert_eq!(sorted("prio"), vec![a, b, c]);
+    assert_eq!(sorted("-prio"), vec![b, a, c]);
+    assert_eq!(sorted("finish"), vec![a, c, b]);
+    assert_eq!(sorted("-finish"), vec![c, a, b]);
+    assert_eq!(sorted("due"), vec![b, a, c]);
+    assert_eq!(sorted("-due"), vec![a, b, c]);
+    assert!("size".parse::<SortOrder>().is_err());
+}
@@ -1,6 +1,6 @@
 use protobuf::{self, Message};
 
-use crate::{pb, random_fg_color, Meta, Node, Screen};
+use crate::{pb, random_fg_color, ArchivedDone, Draw, Meta, Node, Screen};
 
 pub fn serialize_screen(screen: &Screen) -> Vec<u8> {
     let mut screen_pb = pb::Screen::default();
@@ -11,17 +11,46 @@ pub fn serialize_screen(screen: &Screen) -> Vec<u8> {
         .map(|(_, node)| serialize_node(node))
         .collect();
     screen_pb.set_nodes(protobuf::RepeatedField::from_vec(nodes));
-    let arrows = screen
-        .arrows
-        .iter()
-        .map(|&(from, to)| {
+    let plain = screen.arrows.iter().map(|&arrow| (arrow, false));
+    let blocking = screen.blockers.iter().map(|&arrow| (arrow, true));
+    let arrows = plain
+        .chain(blocking)
+        .map(|((from, to), blocking)| {
             let mut arrow_pb = pb::Arrow::default();
             arrow_pb.set_from_node(from);
             arrow_pb.set_to_node(to);
+            if blocking {
+                arrow_pb.set_blocking(true);
+            }
             arrow_pb
         })
         .collect();
     screen_pb.set_arrows(protobuf::RepeatedField::from_vec(arrows));
+    let draws = screen
+        .draws
+        .iter()
+        .map(|draw| {
+            let mut draw_pb = pb::Draw::default();
+            draw_pb.set_node_id(draw.node_id);
+            draw_pb.set_time(draw.time);
+            if let Some(outcome) = draw.outcome {
+                draw_pb.set_outcome(outcome.to_string());
+            }
+            draw_pb
+        })
+        .collect();
+    screen_pb.set_draws(protobuf::RepeatedField::from_vec(draws));
+    let archived_done = screen
+        .archived_done
+        .iter()
+        .map(|done| {
+            let mut done_pb = pb::ArchivedDone::default();
+            done_pb.set_time(done.time);
+            done_pb.set_tags(done.tags.join(" "));
+            done_pb
+        })
+        .collect();
+    screen_pb.set_archived_done(protobuf::RepeatedField::from_vec(archived_done));
     screen_pb.write_to_bytes().unwrap()
 }
 
@@ -36,6 +65,25 @@ fn serialize_meta(meta: &Meta) -> pb::Meta {
     if let Some(finish_time) = meta.finish_time {
         meta_pb.set_finish_time(finish_time);
     }
+    if let Some(due) = meta.due {
+        meta_pb.set_due(due);
+    }
+    meta_pb.set_completions(meta.completions.clone());
+    let intervals = meta
+        .intervals
+        .iter()
+        .map(|&(start, end)| {
+            let mut interval_pb = pb::Interval::default();
+            interval_pb.set_start(start);
+            if let Some(end) = end {
+                interval_pb.set_end(end);
+            }
+            interval_pb
+        })
+        .collect();
+    meta_pb.set_intervals(protobuf::RepeatedField::from_vec(intervals));
+    meta_pb.set_pomodoros(meta.pomodoros.clone());
+    meta_pb.set_skipped(meta.skipped);
     let mut tags = vec![];
     for (tagk, tagv) in &meta.tags {
         let mut tag = pb::Tag::default();
@@ -63,6 +111,12 @@ fn serialize_node(node: &Node) -> pb::Node {
     if let Some(ref free_text) = node.free_text {
         node_pb.set_free_text(free_text.to_owned());
     }
+    if let Some(alias_of) = node.alias_of {
+        node_pb.set_alias_of(alias_of);
+    }
+    if let Some(ref state) = node.state {
+        node_pb.set_state(state.to_owned());
+    }
     node_pb
 }
 
@@ -81,6 +135,21 @@ fn deserialize_meta(meta_pb: &pb::Meta) -> Meta {
         } else {
             None
         },
+        completions: meta_pb.get_completions().to_vec(),
+        intervals: meta_pb
+            .get_intervals()
+            .iter()
+            .map(|interval_pb| {
+                let end = if interval_pb.has_end() {
+                    Some(interval_pb.get_end())
+                } else {
+                    None
+                };
+                (interval_pb.get_start(), end)
+            })
+            .collect(),
+        pomodoros: meta_pb.get_pomodoros().to_vec(),
+        skipped: meta_pb.get_skipped(),
         gps: (gps.get_lat(), gps.get_lon()),
         tags: meta_pb
             .get_tags()
@@ -109,6 +178,16 @@ fn deserialize_node(node_pb: &pb::Node) -> Node {
         },
         color: random_fg_color(),
         auto_arrange: node_pb.get_auto_arrange(),
+        alias_of: if node_pb.has_alias_of() {
+            Some(node_pb.get_alias_of())
+        } else {
+            None
+        },
+        state: if node_pb.has_state() {
+            Some(node_pb.get_state().to_owned())
+        } else {
+            None
+        },
     }
 }
 
@@ -126,13 +205,33 @@ pub fn deserialize_screen(data: Vec<u8>) -> Result<Screen, protobuf::ProtobufErr
         })
         .collect();
 
-    screen.arrows = screen_pb
-        .get_arrows()
+    for arrow_pb in screen_pb.get_arrows() {
+        let arrow = (arrow_pb.get_from_node(), arrow_pb.get_to_node());
+        if arrow_pb.get_blocking() {
+            screen.blockers.push(arrow);
+        } else {
+            screen.arrows.push(arrow);
+        }
+    }
+    screen.draws = screen_pb
+        .get_draws()
+        .iter()
+        .map(|draw_pb| Draw {
+            node_id: draw_pb.get_node_id(),
+            time: draw_pb.get_time(),
+            outcome: draw_pb.get_outcome().parse().ok(),
+        })
+        .collect();
+    screen.archived_done = screen_pb
+        .get_archived_done()
         .iter()
-        .map(|arrow_pb| {
-            let from = arrow_pb.get_from_node();
-            let to = arrow_pb.get_to_node();
-            (from, to)
+        .map(|done_pb| ArchivedDone {
+            time: done_pb.get_time(),
+            tags: done_pb
+                .get_tags()
+                .split_whitespace()
+                .map(|t| t.to_owned())
+                .collect(),
         })
         .collect();
     Ok(screen)
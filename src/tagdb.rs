@@ -2,11 +2,99 @@ use std::collections::{HashMap, HashSet};
 
 use regex::Regex;
 
-use crate::{re_matches, NodeID};
+use crate::{parse_due, parse_estimate, re_matches, Cmp, NodeID, Query};
+
+// the value of a #key=value tag, read as the first of these it parses as
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum TagValue {
+    Int(i64),
+    // minutes, like #est=1h30m
+    Duration(u64),
+    // seconds since the epoch, from YYYY-MM-DD
+    Date(u64),
+    Str(String),
+}
+
+impl TagValue {
+    pub fn parse(value: &str) -> TagValue {
+        if let Ok(int) = value.parse() {
+            TagValue::Int(int)
+        } else if let Some(minutes) = parse_estimate(value) {
+            TagValue::Duration(minutes)
+        } else if let Some(date) = parse_date(value) {
+            TagValue::Date(date)
+        } else {
+            TagValue::Str(value.to_owned())
+        }
+    }
+
+    // reads `other` as the same type as this value, so `#est=90m` can be
+    // compared with `1h`. bare numbers count as minutes, and relative
+    // dates like 3d count forward from now
+    fn parse_like(&self, other: &str) -> Option<TagValue> {
+        match *self {
+            TagValue::Int(_) => other
+                .parse()
+                .ok()
+                .or_else(|| parse_estimate(other).map(|minutes| minutes as i64))
+                .map(TagValue::Int),
+            TagValue::Duration(_) => other
+                .parse()
+                .ok()
+                .or_else(|| parse_estimate(other))
+                .map(TagValue::Duration),
+            TagValue::Date(_) => {
+                if other == "today" {
+                    let day_in_sec = 60 * 60 * 24;
+                    let now = time::get_time().sec as u64;
+                    Some(TagValue::Date(now / day_in_sec * day_in_sec))
+                } else {
+                    parse_due(other).map(TagValue::Date)
+                }
+            },
+            // #prio=high is no bigger or smaller than 3
+            TagValue::Str(_) => match TagValue::parse(other) {
+                TagValue::Str(other) => Some(TagValue::Str(other)),
+                _ => None,
+            },
+        }
+    }
+
+    // whether this value compares with `other` read as the same type.
+    // values that can't be compared with `other` don't match
+    fn compares(&self, cmp: Cmp, other: &str) -> bool {
+        match self
+            .parse_like(other)
+            .and_then(|other| self.partial_cmp(&other))
+        {
+            Some(ordering) => cmp.orders(ordering),
+            None => false,
+        }
+    }
+}
+
+fn parse_date(value: &str) -> Option<u64> {
+    if value.len() != 10 {
+        return None;
+    }
+    time::strptime(value, "%Y-%m-%d")
+        .ok()
+        .map(|tm| tm.to_timespec().sec as u64)
+}
+
+fn tags_like(tags: &HashSet<String>, key: &str, text: &str) -> bool {
+    let text = text.to_lowercase();
+    tags.iter().any(|tag| match tag.split_once('=') {
+        Some((k, value)) => k == key && value.to_lowercase().contains(&text),
+        None => false,
+    })
+}
 
 pub struct TagDB {
     node_to_tags: HashMap<NodeID, HashSet<String>>,
     tag_to_nodes: HashMap<String, HashSet<NodeID>>,
+    // key to the typed value of each node's #key=value, for comparisons
+    values: HashMap<String, HashMap<NodeID, TagValue>>,
 }
 
 impl Default for TagDB {
@@ -14,6 +102,7 @@ impl Default for TagDB {
         TagDB {
             node_to_tags: HashMap::new(),
             tag_to_nodes: HashMap::new(),
+            values: HashMap::new(),
         }
     }
 }
@@ -39,6 +128,17 @@ impl TagDB {
             nodes.insert(node);
 
             self.tag_to_nodes.insert(tag.clone(), nodes);
+
+            // the first value wins when a key is repeated
+            if let Some((key, value)) = tag.split_once('=') {
+                if !value.is_empty() {
+                    self.values
+                        .entry(key.to_owned())
+                        .or_default()
+                        .entry(node)
+                        .or_insert_with(|| TagValue::parse(value));
+                }
+            }
         }
 
         if text.contains('=') {
@@ -64,10 +164,25 @@ impl TagDB {
                 if let Some(nodes) = self.tag_to_nodes.get_mut(tag) {
                     nodes.remove(&node);
                 }
+                if let Some((key, _)) = tag.split_once('=') {
+                    if let Some(values) = self.values.get_mut(key) {
+                        values.remove(&node);
+                    }
+                }
             }
         }
     }
 
+    pub fn node_to_tags(&self, node: NodeID) -> Vec<String> {
+        let mut res: Vec<String> = self
+            .node_to_tags
+            .get(&node)
+            .map(|set| set.clone().into_iter().collect())
+            .unwrap_or_default();
+        res.sort();
+        res
+    }
+
     pub fn tag_to_nodes(&self, tag: &str) -> Vec<NodeID> {
         let mut res = self
             .tag_to_nodes
@@ -77,6 +192,102 @@ impl TagDB {
         res.sort();
         res
     }
+
+    pub fn has_tag(&self, node: NodeID, tag: &str) -> bool {
+        match self.node_to_tags.get(&node) {
+            Some(tags) => tags.contains(tag),
+            None => false,
+        }
+    }
+
+    pub fn value(&self, node: NodeID, key: &str) -> Option<&TagValue> {
+        self.values.get(key).and_then(|values| values.get(&node))
+    }
+
+    // whether the node's #key= value compares with `other` read as the
+    // same type. nodes without the key, or whose value can't be compared
+    // with `other`, don't match
+    pub fn compare(&self, node: NodeID, key: &str, cmp: Cmp, other: &str) -> bool {
+        match self.value(node, key) {
+            Some(value) => value.compares(cmp, other),
+            None => false,
+        }
+    }
+
+    // the nodes whose #key= value compares with `other`, like
+    // compare(node, key, cmp, other)
+    pub fn compared(&self, key: &str, cmp: Cmp, other: &str) -> Vec<NodeID> {
+        let mut res: Vec<NodeID> = match self.values.get(key) {
+            Some(values) => values
+                .keys()
+                .cloned()
+                .filter(|&node| self.compare(node, key, cmp, other))
+                .collect(),
+            None => vec![],
+        };
+        res.sort();
+        res
+    }
+
+    // whether the node matches the tag clauses of a query. clauses about
+    // anything but tags don't match
+    pub fn matches(&self, node: NodeID, query: &Query) -> bool {
+        match *query {
+            Query::And(ref a, ref b) => self.matches(node, a) && self.matches(node, b),
+            Query::Or(ref a, ref b) => self.matches(node, a) || self.matches(node, b),
+            Query::Not(ref q) => !self.matches(node, q),
+            Query::Tag(ref tag) => self.has_tag(node, tag),
+            Query::Value(ref key, cmp, ref value) => self.compare(node, key, cmp, value),
+            Query::Like(ref key, ref text) => self.like(node, key, text),
+            _ => false,
+        }
+    }
+
+    // like matches, for tags that aren't in the index, such as those
+    // kept with an archived completion
+    pub fn matches_tags(tags: &HashSet<String>, query: &Query) -> bool {
+        match *query {
+            Query::And(ref a, ref b) => {
+                TagDB::matches_tags(tags, a) && TagDB::matches_tags(tags, b)
+            },
+            Query::Or(ref a, ref b) => TagDB::matches_tags(tags, a) || TagDB::matches_tags(tags, b),
+            Query::Not(ref q) => !TagDB::matches_tags(tags, q),
+            Query::Tag(ref tag) => tags.contains(tag),
+            Query::Value(ref key, cmp, ref other) => tags
+                .iter()
+                .filter_map(|tag| tag.split_once('='))
+                .any(|(k, value)| {
+                    k == key && !value.is_empty() && TagValue::parse(value).compares(cmp, other)
+                }),
+            Query::Like(ref key, ref text) => tags_like(tags, key, text),
+            _ => false,
+        }
+    }
+
+    // whether the node's #key= value contains `text`, ignoring case
+    pub fn like(&self, node: NodeID, key: &str, text: &str) -> bool {
+        match self.node_to_tags.get(&node) {
+            Some(tags) => tags_like(tags, key, text),
+            None => false,
+        }
+    }
+
+    // everyone a node is #waiting= on
+    pub fn people(&self) -> Vec<String> {
+        let mut people: Vec<String> = self
+            .tag_to_nodes
+            .iter()
+            .filter(|(_, nodes)| !nodes.is_empty())
+            .filter_map(|(tag, _)| tag.strip_prefix("waiting="))
+            .map(|person| person.to_owned())
+            .collect();
+        people.sort();
+        people
+    }
+
+    pub fn waiting_on(&self, person: &str) -> Vec<NodeID> {
+        self.tag_to_nodes(&format!("waiting={}", person))
+    }
 }
 
 #[test]
@@ -92,3 +303,68 @@ fn test_basic_func() {
     assert_eq!(tdb.tag_to_nodes("yes=ok"), vec![3]);
     assert_eq!(tdb.tag_to_nodes("$"), vec![4]);
 }
+
+#[test]
+fn test_values() {
+    let mut tdb = TagDB::default();
+    tdb.reindex(1, "a #prio=3 #est=90m #client=Acme-Corp".to_owned());
+    tdb.reindex(2, "b #prio=1 #est=20m #due=2024-05-01".to_owned());
+    tdb.reindex(
+        3,
+        "c #prio=5 #est=45 #due=2024-06-01 #client=globex".to_owned(),
+    );
+    tdb.reindex(4, "d #prio=high #est".to_owned());
+    assert_eq!(tdb.value(1, "prio"), Some(&TagValue::Int(3)));
+    assert_eq!(tdb.value(1, "est"), Some(&TagValue::Duration(90)));
+    assert_eq!(
+        tdb.value(2, "due"),
+        parse_date("2024-05-01").map(TagValue::Date).as_ref()
+    );
+    assert_eq!(
+        tdb.value(4, "prio"),
+        Some(&TagValue::Str("high".to_owned()))
+    );
+    assert_eq!(tdb.value(4, "est"), None);
+
+    assert_eq!(tdb.compared("prio", Cmp::Ge, "3"), vec![1, 3]);
+    assert_eq!(tdb.compared("prio", Cmp::Lt, "10"), vec![1, 2, 3]);
+    assert_eq!(tdb.compared("est", Cmp::Lt, "1h"), vec![2, 3]);
+    assert_eq!(tdb.compared("due", Cmp::Le, "2024-05-15"), vec![2]);
+    assert!(tdb.compared("due", Cmp::Gt, "today").is_empty());
+    assert!(tdb.like(1, "client", "acme"));
+    assert!(!tdb.like(3, "client", "acme"));
+
+    tdb.reindex(3, "c #prio=2".to_owned());
+    assert_eq!(tdb.compared("prio", Cmp::Ge, "3"), vec![1]);
+    assert_eq!(tdb.compared("est", Cmp::Lt, "1h"), vec![2]);
+    tdb.remove(1);
+    assert_eq!(tdb.value(1, "prio"), None);
+}
+
+#[test]
+fn test_matches_tags() {
+    let tags: HashSet<String> = ["client", "client=Acme-Corp", "est", "est=90m", "urgent"]
+        .iter()
+        .map(|&tag| tag.to_owned())
+        .collect();
+    let matches = |query: &str| TagDB::matches_tags(&tags, &query.parse().unwrap());
+    assert!(matches("#urgent"));
+    assert!(matches("#client=Acme-Corp"));
+    assert!(matches("#est>1h and #client~acme"));
+    assert!(!matches("#est<1h"));
+    assert!(!matches("#urgent and not #client"));
+    assert!(matches("#later or #est>=90"));
+}
+
+#[test]
+fn test_people() {
+    let mut tdb = TagDB::default();
+    tdb.reindex(1, "contract #waiting=legal".to_owned());
+    tdb.reindex(2, "invoice #waiting=bob #followup=2020-01-01".to_owned());
+    tdb.reindex(3, "report #waiting=bob".to_owned());
+    tdb.reindex(4, "all of it #waiting".to_owned());
+    assert_eq!(tdb.people(), vec!["bob", "legal"]);
+    assert_eq!(tdb.waiting_on("bob"), vec![2, 3]);
+    tdb.reindex(1, "contract".to_owned());
+    assert_eq!(tdb.people(), vec!["bob"]);
+}
@@ -0,0 +1,303 @@
+use json::{self, JsonValue};
+use regex::Regex;
+
+use crate::{todotxt::projects, NodeID, Screen, TodoTxtTask};
+
+// the fields of a task from `task export` that void has a place for
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TaskwarriorTask {
+    pub uuid: String,
+    pub description: String,
+    pub status: String,
+    pub project: Option<String>,
+    pub tags: Vec<String>,
+    pub priority: Option<String>,
+    // void's exact #prio=, which H, M and L can't hold. taskwarrior keeps
+    // attributes it doesn't know about as orphaned UDAs
+    pub void_prio: Option<usize>,
+    pub entry: Option<u64>,
+    pub modified: Option<u64>,
+    pub end: Option<u64>,
+    pub due: Option<u64>,
+}
+
+impl TaskwarriorTask {
+    fn from_json(value: &JsonValue) -> Option<TaskwarriorTask> {
+        let string = |key: &str| value[key].as_str().map(|s| s.to_owned());
+        let date = |key: &str| value[key].as_str().and_then(parse_time);
+        Some(TaskwarriorTask {
+            uuid: string("uuid")?,
+            description: string("description")?,
+            status: string("status").unwrap_or_else(|| "pending".to_owned()),
+            project: string("project"),
+            tags: value["tags"]
+                .members()
+                .filter_map(|t| t.as_str())
+                .map(|t| t.to_owned())
+                .collect(),
+            priority: string("priority"),
+            void_prio: value["void_prio"].as_usize(),
+            entry: date("entry"),
+            modified: date("modified"),
+            end: date("end"),
+            due: date("due"),
+        })
+    }
+
+    fn to_json(&self) -> JsonValue {
+        let mut value = JsonValue::new_object();
+        value["uuid"] = self.uuid.clone().into();
+        value["description"] = self.description.clone().into();
+        value["status"] = self.status.clone().into();
+        if let Some(ref project) = self.project {
+            value["project"] = project.clone().into();
+        }
+        if !self.tags.is_empty() {
+            value["tags"] = self.tags.clone().into();
+        }
+        if let Some(ref priority) = self.priority {
+            value["priority"] = priority.clone().into();
+        }
+        if let Some(prio) = self.void_prio {
+            value["void_prio"] = prio.into();
+        }
+        let dates = [
+            ("entry", self.entry),
+            ("modified", self.modified),
+            ("end", self.end),
+            ("due", self.due),
+        ];
+        for &(key, date) in &dates {
+            if let Some(date) = date {
+                value[key] = format_time(date).into();
+            }
+        }
+        value
+    }
+
+    // the same task as a todo.txt line for `node`, which is how it's
+    // applied to the db. dotted projects are nested project nodes
+    pub fn to_todotxt(&self, node: Option<NodeID>) -> TodoTxtTask {
+        let mut text = self.description.clone();
+        for tag in &self.tags {
+            text.push_str(&format!(" #{}", tag));
+        }
+        // the exact priority holds unless it was changed in taskwarrior.
+        // H, M and L are ranked like todo.txt's (A), (C) and (E), and
+        // urgency is left out since it's taskwarrior's own blend of
+        // everything else about the task
+        let exact = self
+            .void_prio
+            .filter(|&prio| priority_name(prio).as_deref() == self.priority.as_deref());
+        let prio = match (exact, self.priority.as_deref()) {
+            (Some(prio), _) => Some(prio),
+            (None, Some("H")) => Some(5),
+            (None, Some("M")) => Some(3),
+            (None, Some("L")) => Some(1),
+            _ => None,
+        };
+        TodoTxtTask {
+            id: node,
+            done: self.status == "completed",
+            finished: self.end,
+            created: self.entry,
+            prio,
+            text,
+            projects: self
+                .project
+                .as_ref()
+                .map(|p| p.split('.').map(|p| p.to_owned()).collect())
+                .unwrap_or_default(),
+            due: self.due,
+        }
+    }
+}
+
+// reads the output of `task export`, which is either a json array or
+// one object per line in older versions
+pub fn parse_taskwarrior(text: &str) -> Result<Vec<TaskwarriorTask>, json::Error> {
+    let values = match json::parse(text) {
+        Ok(JsonValue::Array(values)) => values,
+        Ok(value) => vec![value],
+        Err(_) => {
+            let lines = text
+                .lines()
+                .map(|l| l.trim().trim_end_matches(','))
+                .filter(|l| !l.is_empty());
+            let mut values = vec![];
+            for line in lines {
+                values.push(json::parse(line)?);
+            }
+            values
+        },
+    };
+    Ok(values
+        .iter()
+        .filter_map(TaskwarriorTask::from_json)
+        .collect())
+}
+
+// a `task import`able json array of the leaves beneath #task roots
+pub fn taskwarrior(screen: &Screen) -> String {
+    lazy_static! {
+        static ref RE_PRIO: Regex = Regex::new(r"#prio=(\d+)").unwrap();
+    }
+    let mut tasks = vec![];
+    for node_id in screen.task_leaves() {
+        let node = &screen.nodes[&node_id];
+        let prio = RE_PRIO
+            .captures(&node.content)
+            .and_then(|cap| cap[1].parse::<usize>().ok());
+        // bare #tags become taskwarrior tags, #key=value ones stay put
+        let mut description = vec![];
+        let mut tags = vec![];
+        for word in node.content.split_whitespace() {
+            if word.starts_with("#prio=") || word.starts_with("#due=") {
+                continue;
+            } else if word.len() > 1 && word.starts_with('#') && !word.contains('=') {
+                tags.push(word[1..].to_owned());
+            } else {
+                description.push(word);
+            }
+        }
+        let projects = projects(screen, node_id);
+        let task = TaskwarriorTask {
+            uuid: node
+                .meta
+                .tags
+                .get("uuid")
+                .cloned()
+                .unwrap_or_else(|| uuid_for_node(node_id)),
+            description: description.join(" "),
+            status: if node.stricken {
+                "completed".to_owned()
+            } else {
+                "pending".to_owned()
+            },
+            project: if projects.is_empty() {
+                None
+            } else {
+                Some(projects.join("."))
+            },
+            tags,
+            priority: prio.and_then(priority_name),
+            void_prio: prio,
+            entry: Some(node.meta.ctime),
+            modified: Some(node.meta.mtime),
+            end: node.meta.finish_time.filter(|_| node.stricken),
+            due: node.meta.due,
+        };
+        tasks.push(task.to_json());
+    }
+
+    let lines: Vec<String> = tasks.iter().map(|t| t.dump()).collect();
+    format!("[\n{}\n]\n", lines.join(",\n"))
+}
+
+fn priority_name(prio: usize) -> Option<String> {
+    match prio {
+        0 => None,
+        1 | 2 => Some("L".to_owned()),
+        3 | 4 => Some("M".to_owned()),
+        _ => Some("H".to_owned()),
+    }
+}
+
+// nodes that came from void get a uuid made from their id, so exporting
+// again doesn't duplicate them in taskwarrior
+fn uuid_for_node(node_id: NodeID) -> String { format!("766f6964-0000-4000-8000-{:012x}", node_id) }
+
+pub fn node_from_uuid(uuid: &str) -> Option<NodeID> {
+    if !uuid.starts_with("766f6964-0000-4000-8000-") {
+        return None;
+    }
+    NodeID::from_str_radix(&uuid[24..], 16).ok()
+}
+
+fn parse_time(date: &str) -> Option<u64> {
+    time::strptime(date, "%Y%m%dT%H%M%SZ")
+        .ok()
+        .map(|tm| tm.to_timespec().sec as u64)
+}
+
+fn format_time(t: u64) -> String {
+    let tm = time::at_utc(time::Timespec::new(t as i64, 0));
+    time::strftime("%Y%m%dT%H%M%SZ", &tm).unwrap()
+}
+
+#[test]
+fn test_taskwarrior_json() {
+    // one task per line, like `task export` writes them
+    let export = concat!(
+        "[\n",
+        r#"{"id":1,"description":"call mom","entry":"20240301T120000Z","#,
+        r#""modified":"20240302T080000Z","project":"home.family","status":"pending","#,
+        r#""tags":["phone"],"uuid":"a1b2c3d4-0000-4000-8000-000000000001","urgency":4.9,"#,
+        r#""due":"20240305T000000Z"},"#,
+        "\n",
+        r#"{"id":0,"description":"file taxes","end":"20240302T090000Z","#,
+        r#""entry":"20240301T120000Z","priority":"H","status":"completed","#,
+        r#""uuid":"766f6964-0000-4000-8000-00000000002a","urgency":0}"#,
+        "\n]",
+    );
+    let tasks = parse_taskwarrior(export).unwrap();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].project.as_deref(), Some("home.family"));
+    assert_eq!(tasks[0].tags, vec!["phone"]);
+    assert_eq!(tasks[0].due, parse_time("20240305T000000Z"));
+    assert_eq!(node_from_uuid(&tasks[0].uuid), None);
+    assert_eq!(node_from_uuid(&tasks[1].uuid), Some(42));
+    assert_eq!(uuid_for_node(42), tasks[1].uuid);
+
+    let todo = tasks[0].to_todotxt(None);
+    assert_eq!(todo.projects, vec!["home", "family"]);
+    assert_eq!(todo.prio, None);
+    assert_eq!(todo.content(), "call mom #phone #due=2024-03-05");
+    let todo = tasks[1].to_todotxt(Some(42));
+    assert!(todo.done);
+    assert_eq!(
+        todo.prio,
+        TodoTxtTask::parse("(A) file taxes").unwrap().prio
+    );
+    assert_eq!(todo.finished, parse_time("20240302T090000Z"));
+
+    let lines = export
+        .lines()
+        .skip(1)
+        .take(2)
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert_eq!(parse_taskwarrior(&lines).unwrap(), tasks);
+    assert_eq!(format_time(tasks[1].end.unwrap()), "20240302T090000Z");
+    // exact priorities come back from a round trip
+    for &prio in &[None, Some(0), Some(2), Some(7)] {
+        let task = TaskwarriorTask {
+            uuid: uuid_for_node(7),
+            description: "never pick me".to_owned(),
+            status: "pending".to_owned(),
+            priority: prio.and_then(priority_name),
+            void_prio: prio,
+            ..TaskwarriorTask::default()
+        };
+        let json = task.to_json().dump();
+        let back = parse_taskwarrior(&json).unwrap().remove(0);
+        assert_eq!(back, task);
+        assert_eq!(back.to_todotxt(Some(7)).prio, prio);
+    }
+    // a priority changed in taskwarrior wins over the exact one
+    let changed = TaskwarriorTask {
+        priority: Some("L".to_owned()),
+        void_prio: Some(7),
+        ..tasks[1].clone()
+    };
+    assert_eq!(changed.to_todotxt(Some(42)).prio, Some(1));
+    for &(letter, name) in &[("(A)", "H"), ("(C)", "M"), ("(E)", "L")] {
+        let prio = TodoTxtTask::parse(&format!("{} x", letter)).unwrap().prio;
+        assert_eq!(prio.and_then(priority_name).as_deref(), Some(name));
+    }
+
+    assert_eq!(
+        TaskwarriorTask::from_json(&tasks[1].to_json()).unwrap(),
+        tasks[1]
+    );
+}
@@ -0,0 +1,336 @@
+use regex::Regex;
+
+use crate::{format_date, Node, NodeID, Screen};
+
+// one line of a todo.txt file. void:<id> ties a line back to the node
+// it was exported from, so importing it again updates that node
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct TodoTxtTask {
+    pub id: Option<NodeID>,
+    pub done: bool,
+    pub finished: Option<u64>,
+    pub created: Option<u64>,
+    pub prio: Option<usize>,
+    pub text: String,
+    pub projects: Vec<String>,
+    pub due: Option<u64>,
+}
+
+impl TodoTxtTask {
+    pub fn parse(line: &str) -> Option<TodoTxtTask> {
+        let mut tokens = line.split_whitespace().peekable();
+        tokens.peek()?;
+        let mut task = TodoTxtTask::default();
+
+        if tokens.peek() == Some(&"x") {
+            tokens.next();
+            task.done = true;
+            if let Some(finished) = tokens.peek().and_then(|t| parse_date(t)) {
+                tokens.next();
+                task.finished = Some(finished);
+            }
+        } else if let Some(prio) = tokens.peek().and_then(|t| parse_priority(t)) {
+            tokens.next();
+            task.prio = Some(prio);
+        }
+        if let Some(created) = tokens.peek().and_then(|t| parse_date(t)) {
+            tokens.next();
+            task.created = Some(created);
+        }
+
+        let mut words = vec![];
+        for token in tokens {
+            if token.len() > 1 && token.starts_with('+') {
+                task.projects.push(token[1..].to_owned());
+            } else if token.starts_with("due:") && parse_date(&token[4..]).is_some() {
+                task.due = parse_date(&token[4..]);
+            } else if token.starts_with("void:") && token[5..].parse::<NodeID>().is_ok() {
+                task.id = token[5..].parse().ok();
+            } else if token.starts_with("pri:") && token.len() == 5 {
+                task.prio = token[4..].chars().next().and_then(letter_priority);
+            } else {
+                words.push(token.to_owned());
+            }
+        }
+        // an exact #prio=N wins over the letter as long as it's in the
+        // same bucket, so (A) #prio=7 stays 7 while an edited letter wins
+        let exact = words.iter().position(|w| exact_prio(w).is_some());
+        if let Some(pos) = exact {
+            let prio = exact_prio(&words[pos]).unwrap();
+            if priority_letter(prio) == task.prio.and_then(priority_letter) {
+                task.prio = Some(prio);
+            }
+            words.remove(pos);
+        }
+        task.text = words.join(" ");
+        Some(task)
+    }
+
+    // keeps a node's current priority when this task's is the same
+    // bucket, since a letter can't tell #prio=7 from #prio=5. priorities
+    // no letter stands for were given exactly and are left alone
+    pub fn keep_prio(&mut self, current: usize) {
+        let from_letter = match self.prio {
+            Some(prio) => has_letter(prio),
+            None => true,
+        };
+        if from_letter && priority_letter(current) == self.prio.and_then(priority_letter) {
+            self.prio = Some(current);
+        }
+    }
+
+    pub fn to_line(&self) -> String {
+        let mut parts = vec![];
+        if self.done {
+            parts.push("x".to_owned());
+            if let Some(finished) = self.finished {
+                parts.push(format_date(finished));
+            }
+        } else if let Some(letter) = self.prio.and_then(priority_letter) {
+            parts.push(format!("({})", letter));
+        }
+        // priorities without a letter of their own are kept as tags
+        let inexact = self.prio.filter(|&prio| !has_letter(prio));
+        // a creation date can only follow a completion date on done lines
+        if let Some(created) = self.created {
+            if !self.done || self.finished.is_some() {
+                parts.push(format_date(created));
+            }
+        }
+
+        // @contexts are the same in void and todo.txt, so the text is
+        // written as it is
+        for word in self.text.split_whitespace() {
+            parts.push(word.to_owned());
+        }
+        for project in &self.projects {
+            parts.push(format!("+{}", project));
+        }
+        if let Some(prio) = inexact {
+            parts.push(format!("#prio={}", prio));
+        }
+        if self.done {
+            if let Some(letter) = self.prio.and_then(priority_letter) {
+                parts.push(format!("pri:{}", letter));
+            }
+        }
+        if let Some(due) = self.due {
+            parts.push(format!("due:{}", format_date(due)));
+        }
+        if let Some(id) = self.id {
+            parts.push(format!("void:{}", id));
+        }
+        parts.join(" ")
+    }
+
+    // whether the node could be the one this line was exported from.
+    // a void:<id> from another db names some unrelated node here, so
+    // the creation date or the text has to agree as well
+    pub fn exported_from(&self, node: &Node) -> bool {
+        let created = self.created.map(format_date) == Some(format_date(node.meta.ctime));
+        created || words(&self.text) == words(&node.content)
+    }
+
+    // whether node content already says what this line does
+    pub fn same_content(&self, content: &str) -> bool {
+        let prio = RE_PRIO
+            .captures(content)
+            .and_then(|cap| cap[1].parse().ok());
+        prio == self.prio && words(&self.text) == words(content)
+    }
+
+    // the node content for this line, with priority and due date as tags
+    pub fn content(&self) -> String {
+        let mut content = self.text.clone();
+        if let Some(prio) = self.prio {
+            content.push_str(&format!(" #prio={}", prio));
+        }
+        if let Some(due) = self.due {
+            content.push_str(&format!(" #due={}", format_date(due)));
+        }
+        content.trim().to_owned()
+    }
+}
+
+lazy_static! {
+    static ref RE_PRIO: Regex = Regex::new(r"#prio=(\d+)").unwrap();
+    static ref RE_STRIP: Regex = Regex::new(r"\s*#(prio|due)=\S*").unwrap();
+}
+
+// the words of content besides #prio= and #due=, which move to the end
+// on import
+fn words(content: &str) -> Vec<String> {
+    RE_STRIP
+        .replace_all(content, "")
+        .split_whitespace()
+        .map(|word| word.to_owned())
+        .collect()
+}
+
+// a todo.txt line for every leaf beneath a #task root, done or not
+pub fn todotxt(screen: &Screen) -> String {
+    let mut out = String::new();
+    for node_id in screen.task_leaves() {
+        let node = &screen.nodes[&node_id];
+        let task = TodoTxtTask {
+            id: Some(node_id),
+            done: node.stricken,
+            finished: node.meta.finish_time,
+            created: Some(node.meta.ctime),
+            prio: RE_PRIO
+                .captures(&node.content)
+                .and_then(|cap| cap[1].parse().ok()),
+            text: RE_STRIP.replace_all(&node.content, "").trim().to_owned(),
+            projects: projects(screen, node_id),
+            due: node.meta.due,
+        };
+        out.push_str(&task.to_line());
+        out.push('\n');
+    }
+    out
+}
+
+// the names of a node's ancestors below the root, from the top down
+pub fn projects(screen: &Screen, node_id: NodeID) -> Vec<String> {
+    let mut projects = vec![];
+    let mut cursor = screen.nodes[&node_id].parent_id;
+    while cursor != 0 {
+        let node = match screen.nodes.get(&cursor) {
+            Some(node) => node,
+            None => break,
+        };
+        let name = project_name(&node.content);
+        if !name.is_empty() {
+            projects.push(name);
+        }
+        cursor = node.parent_id;
+    }
+    projects.reverse();
+    projects
+}
+
+// node content as a +project: tags dropped, words joined by dashes
+pub fn project_name(content: &str) -> String {
+    content
+        .split_whitespace()
+        .filter(|word| !word.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn parse_date(date: &str) -> Option<u64> {
+    if date.len() != 10 {
+        return None;
+    }
+    time::strptime(date, "%Y-%m-%d")
+        .ok()
+        .map(|tm| tm.to_timespec().sec as u64)
+}
+
+// (A) is the most urgent, and so the highest #prio
+fn parse_priority(token: &str) -> Option<usize> {
+    let bytes = token.as_bytes();
+    if bytes.len() != 3 || bytes[0] != b'(' || bytes[2] != b')' {
+        return None;
+    }
+    letter_priority(bytes[1] as char)
+}
+
+fn letter_priority(letter: char) -> Option<usize> {
+    match letter {
+        'A' => Some(5),
+        'B' => Some(4),
+        'C' => Some(3),
+        'D' => Some(2),
+        'E'..='Z' => Some(1),
+        _ => None,
+    }
+}
+
+// whether a letter stands for exactly this priority
+fn has_letter(prio: usize) -> bool { priority_letter(prio).and_then(letter_priority) == Some(prio) }
+
+fn exact_prio(word: &str) -> Option<usize> { word.strip_prefix("#prio=")?.parse().ok() }
+
+fn priority_letter(prio: usize) -> Option<char> {
+    match prio {
+        0 => None,
+        1 => Some('E'),
+        2 => Some('D'),
+        3 => Some('C'),
+        4 => Some('B'),
+        _ => Some('A'),
+    }
+}
+
+#[test]
+fn test_todotxt_lines() {
+    let line = "(B) 2024-03-01 call mom @phone +home +family due:2024-03-05 void:12";
+    let task = TodoTxtTask::parse(line).unwrap();
+    assert_eq!(task.id, Some(12));
+    assert_eq!(task.prio, Some(4));
+    assert_eq!(task.text, "call mom @phone");
+    assert_eq!(task.projects, vec!["home", "family"]);
+    assert_eq!(task.created, parse_date("2024-03-01"));
+    assert_eq!(task.due, parse_date("2024-03-05"));
+    assert!(!task.done);
+    assert_eq!(task.to_line(), line);
+    assert_eq!(task.content(), "call mom @phone #prio=4 #due=2024-03-05");
+
+    // tags aren't contexts, and stay tags
+    let line = "call mom #phone @home";
+    let task = TodoTxtTask::parse(line).unwrap();
+    assert_eq!(task.text, line);
+    assert_eq!(task.to_line(), line);
+
+    let line = "x 2024-03-02 2024-03-01 file taxes #est=2h pri:A void:3";
+    let task = TodoTxtTask::parse(line).unwrap();
+    assert!(task.done);
+    assert_eq!(task.finished, parse_date("2024-03-02"));
+    assert_eq!(task.prio, Some(5));
+    assert_eq!(task.text, "file taxes #est=2h");
+    assert_eq!(task.to_line(), line);
+
+    assert_eq!(TodoTxtTask::parse("   "), None);
+    assert_eq!(
+        TodoTxtTask::parse("x marks the spot").unwrap().text,
+        "marks the spot"
+    );
+    assert_eq!(project_name("Big Launch #task #prio=2"), "Big-Launch");
+
+    // priorities a letter can't hold survive a round trip
+    for &prio in &[0, 7] {
+        let task = TodoTxtTask {
+            prio: Some(prio),
+            text: "never pick me".to_owned(),
+            ..TodoTxtTask::default()
+        };
+        let line = task.to_line();
+        assert_eq!(TodoTxtTask::parse(&line).unwrap(), task);
+        let done = TodoTxtTask {
+            done: true,
+            ..task.clone()
+        };
+        assert_eq!(TodoTxtTask::parse(&done.to_line()).unwrap(), done);
+    }
+    assert_eq!(
+        TodoTxtTask::parse("(A) big #prio=7").unwrap().to_line(),
+        "(A) big #prio=7"
+    );
+    // an edited letter wins over a stale exact priority
+    let task = TodoTxtTask::parse("(C) big #prio=7").unwrap();
+    assert_eq!(task.prio, Some(3));
+    assert_eq!(task.text, "big");
+
+    let mut task = TodoTxtTask::parse("(A) big").unwrap();
+    task.keep_prio(9);
+    assert_eq!(task.prio, Some(9));
+    task.keep_prio(2);
+    assert_eq!(task.prio, Some(9));
+    let mut task = TodoTxtTask::parse("(A) big #prio=7").unwrap();
+    task.keep_prio(5);
+    assert_eq!(task.prio, Some(7));
+    let mut task = TodoTxtTask::parse("small").unwrap();
+    task.keep_prio(0);
+    assert_eq!(task.prio, Some(0));
+}
@@ -37,6 +37,18 @@ impl Arbitrary for Op {
             Event::Key(Key::Ctrl('n')),
             Event::Key(Key::Ctrl(c)),
             Event::Key(Key::Ctrl(u)),
+            Event::Key(Key::Alt('y')),
+            Event::Key(Key::Alt('a')),
+            Event::Key(Key::Alt('r')),
+            Event::Key(Key::Alt('t')),
+            Event::Key(Key::Alt('T')),
+            Event::Key(Key::Alt('o')),
+            Event::Key(Key::Alt('s')),
+            Event::Key(Key::Alt('w')),
+            Event::Key(Key::Alt('v')),
+            Event::Key(Key::Alt('x')),
+            Event::Key(Key::Alt('<')),
+            Event::Key(Key::Alt('>')),
             Event::Key(Key::PageUp),
             Event::Key(Key::PageDown),
             Event::Key(Key::Esc),
@@ -84,10 +96,51 @@ impl Arbitrary for Content {
             "#n=100",
             "#plot=done",
             "#plot=open",
+            "#plot=time",
+            "#plot=pomodoros",
+            "#plot=burndown",
             "#plot=",
             "#plot=InVaLiD",
             "#done",
             "#open",
+            "#sort=prio",
+            "#sort=-due",
+            "#sort=alpha",
+            "#sort=-done",
+            "#sort=InVaLiD",
+            "#template=trip",
+            "#every=1d",
+            "#every=weekday",
+            "#every=0w",
+            "#due=2d",
+            "#due=2020-01-01",
+            "#due=whenever",
+            "#est=90m",
+            "#est=2h",
+            "#est=soon",
+            "#agenda",
+            "#draws",
+            "#kanban",
+            "#kanban=state",
+            "#kanban=prio",
+            "#blocked",
+            "#ready",
+            "#state=doing",
+            "#state=cancelled",
+            "#autocomplete",
+            "@home",
+            "#ctx=office",
+            "#energy=low",
+            "#waiting=bob",
+            "#followup=1d",
+            "#waiting",
+            "#query tag:task and not is:done",
+            "#query (#cat or #dog) mtime<7d",
+            "#query (",
+            "#query #prio>=3 or #est<1h",
+            "#tagged=prio=2..4",
+            "#tagged=client~acme",
+            "{date}",
             "#InVaLiD",
             "kontent",
         ];